@@ -0,0 +1,219 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+/// Attributes of an *identifier*, by name.
+pub type Attributes = BTreeMap<String, Value>;
+
+/// Typed value of an attribute.
+///
+/// In human-readable formats, such as JSON, values are written as plain
+/// booleans, numbers or strings. Binary formats store the type
+/// explicitly.
+#[derive(Clone, Debug)]
+pub enum Value {
+    /// A boolean flag.
+    Bool(bool),
+    /// A whole number.
+    Integer(i64),
+    /// A floating-point number.
+    Float(f64),
+    /// Free text.
+    Text(String),
+}
+
+// Representations used for serialization. Binary formats, like bincode,
+// cannot guess the type of the value, so they use the tagged one.
+#[derive(Deserialize, Serialize)]
+enum Tagged {
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    Text(String),
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum Untagged {
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    Text(String),
+}
+
+impl Serialize for Value {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            match self {
+                Value::Bool(v) => Untagged::Bool(*v),
+                Value::Integer(v) => Untagged::Integer(*v),
+                Value::Float(v) => Untagged::Float(*v),
+                Value::Text(v) => Untagged::Text(v.clone()),
+            }
+            .serialize(serializer)
+        } else {
+            match self {
+                Value::Bool(v) => Tagged::Bool(*v),
+                Value::Integer(v) => Tagged::Integer(*v),
+                Value::Float(v) => Tagged::Float(*v),
+                Value::Text(v) => Tagged::Text(v.clone()),
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            Ok(match Untagged::deserialize(deserializer)? {
+                Untagged::Bool(v) => Value::Bool(v),
+                Untagged::Integer(v) => Value::Integer(v),
+                Untagged::Float(v) => Value::Float(v),
+                Untagged::Text(v) => Value::Text(v),
+            })
+        } else {
+            Ok(match Tagged::deserialize(deserializer)? {
+                Tagged::Bool(v) => Value::Bool(v),
+                Tagged::Integer(v) => Value::Integer(v),
+                Tagged::Float(v) => Value::Float(v),
+                Tagged::Text(v) => Value::Text(v),
+            })
+        }
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Value {
+    /// Values of the same type are compared, as well as numbers,
+    /// whether they are integers or floating-point numbers.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Value::Bool(a), Value::Bool(b)) => a.partial_cmp(b),
+            (Value::Integer(a), Value::Integer(b)) => a.partial_cmp(b),
+            (Value::Integer(a), Value::Float(b)) => (*a as f64).partial_cmp(b),
+            (Value::Float(a), Value::Integer(b)) => a.partial_cmp(&(*b as f64)),
+            (Value::Float(a), Value::Float(b)) => a.partial_cmp(b),
+            (Value::Text(a), Value::Text(b)) => a.partial_cmp(b),
+            _ => None,
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(v: bool) -> Self {
+        Value::Bool(v)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(v: i64) -> Self {
+        Value::Integer(v)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(v: f64) -> Self {
+        Value::Float(v)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(v: &str) -> Self {
+        Value::Text(v.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(v: String) -> Self {
+        Value::Text(v)
+    }
+}
+
+/// Condition on the attributes of an *identifier*.
+///
+/// Conditions on attributes which are not defined are not satisfied,
+/// nor are order comparisons between values which cannot be compared.
+#[derive(Clone, Debug)]
+pub enum Predicate {
+    /// The attribute is defined.
+    Exists(String),
+    /// The attribute is equal to the value.
+    Equal(String, Value),
+    /// The attribute is defined, and different from the value.
+    NotEqual(String, Value),
+    /// The attribute is strictly smaller than the value.
+    Less(String, Value),
+    /// The attribute is smaller than, or equal to the value.
+    LessOrEqual(String, Value),
+    /// The attribute is strictly greater than the value.
+    Greater(String, Value),
+    /// The attribute is greater than, or equal to the value.
+    GreaterOrEqual(String, Value),
+    /// The attribute is equal to one of the values.
+    OneOf(String, Vec<Value>),
+}
+
+impl Predicate {
+    /// Name of the attribute this condition applies to.
+    pub fn attribute(&self) -> &str {
+        match self {
+            Predicate::Exists(name)
+            | Predicate::Equal(name, _)
+            | Predicate::NotEqual(name, _)
+            | Predicate::Less(name, _)
+            | Predicate::LessOrEqual(name, _)
+            | Predicate::Greater(name, _)
+            | Predicate::GreaterOrEqual(name, _)
+            | Predicate::OneOf(name, _) => name,
+        }
+    }
+
+    /// Check if the condition is satisfied by a set of attributes.
+    ///
+    /// # Parameters
+    ///
+    ///  * `attributes`:
+    ///      Attributes of an *identifier*.
+    pub fn matches(&self, attributes: &Attributes) -> bool {
+        let attribute = match attributes.get(self.attribute()) {
+            None => return false,
+            Some(attribute) => attribute,
+        };
+
+        let compare = |value: &Value| attribute.partial_cmp(value);
+
+        match self {
+            Predicate::Exists(_) => true,
+            Predicate::Equal(_, value) => compare(value) == Some(Ordering::Equal),
+            Predicate::NotEqual(_, value) => compare(value) != Some(Ordering::Equal),
+            Predicate::Less(_, value) => compare(value) == Some(Ordering::Less),
+            Predicate::LessOrEqual(_, value) => {
+                matches!(compare(value), Some(Ordering::Less) | Some(Ordering::Equal))
+            }
+            Predicate::Greater(_, value) => compare(value) == Some(Ordering::Greater),
+            Predicate::GreaterOrEqual(_, value) => {
+                matches!(
+                    compare(value),
+                    Some(Ordering::Greater) | Some(Ordering::Equal)
+                )
+            }
+            Predicate::OneOf(_, values) => values.iter().any(|value| attribute == value),
+        }
+    }
+}
@@ -1,13 +1,29 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+use std::thread;
+
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::attributes::Attributes;
+use super::attributes::Predicate;
+use super::mapped_index::MappedTable;
 use super::space::Position;
 use super::space::Shape;
 use super::space::Space;
 use super::space_db::SpaceDB;
 use super::space_index::SpaceSetObject;
+use super::table::Table;
 use super::DataBase;
+use super::Error;
+use super::Histogram;
 use super::ResultSet;
+use crate::storage::model::SpatialObject;
+
+// Positions with the properties of their identifier, as decoded
+// coordinates of a space, and as positions of the Universe.
+pub(crate) type DecodedPositions<'c> = Vec<(&'c Properties, Vec<f64>, Position)>;
 
 /// Query Parameters.
 pub struct CoreQueryParameters<'a> {
@@ -23,6 +39,11 @@ pub struct CoreQueryParameters<'a> {
     pub view_port: &'a Option<(Vec<f64>, Vec<f64>)>,
     /// Index resolution to use.
     pub resolution: &'a Option<Vec<u32>>,
+    /// Conditions on the attributes of the *identifiers*, which all have
+    /// to be satisfied for their positions to be returned. The attributes
+    /// of the results are available through
+    /// [Core::attributes](struct.Core.html#method.attributes).
+    pub filters: &'a [Predicate],
 }
 
 impl CoreQueryParameters<'_> {
@@ -50,8 +71,8 @@ impl CoreQueryParameters<'_> {
 /// Definition of the volumetric objects identifiers.
 ///
 /// We have two parts to it, first the *kind* and the actual, *id* used
-/// to distinguish different objects.
-// FIXME: Ids are expected unique, irrespective of the enum variant!
+/// to distinguish different objects. Objects of different kinds can
+/// share the same *id*.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum Properties {
     /// Spatial Features.
@@ -108,6 +129,85 @@ impl Properties {
     {
         Properties::Unknown(id.into(), type_name.into())
     }
+
+    /// Instantiate a new object of the given kind, with the given id.
+    ///
+    /// Objects of kind `Feature` are instantiated as *features*, any
+    /// other kind as an arbitrary kind of object.
+    ///
+    /// # Parameters
+    ///
+    ///  * `id`:
+    ///      The identifier of the object, which can be converted into a
+    ///      `String`.
+    ///
+    ///  * `type_name`:
+    ///      A value which can be converted into a `String`, and
+    ///      represent the **kind** of the object.
+    pub fn new<S>(id: S, type_name: S) -> Properties
+    where
+        S: Into<String>,
+    {
+        let type_name = type_name.into();
+
+        if type_name == "Feature" {
+            Properties::Feature(id.into())
+        } else {
+            Properties::Unknown(id.into(), type_name)
+        }
+    }
+
+    // Key identifying an object within a dataset, ordered by *id* first.
+    pub(crate) fn key(&self) -> (&str, &str) {
+        (self.id(), self.type_name())
+    }
+}
+
+/// Spatial summary of the positions of an *identifier* within a
+/// reference space.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Summary {
+    /// Number of positions.
+    pub count: usize,
+    /// Lowest corner of the minimum bounding box of the positions.
+    pub lower: Position,
+    /// Highest corner of the minimum bounding box of the positions.
+    pub higher: Position,
+    /// Mean of the positions.
+    pub centroid: Position,
+}
+
+impl Summary {
+    // Summarise decoded positions, if there are any.
+    fn new(positions: &[Position]) -> Option<Self> {
+        let first: Vec<f64> = positions.first()?.into();
+
+        let mut lower = first.clone();
+        let mut higher = first;
+        let mut sum = vec![0.0; lower.len()];
+
+        for position in positions {
+            let position: Vec<f64> = position.into();
+            for (k, c) in position.into_iter().enumerate() {
+                lower[k] = lower[k].min(c);
+                higher[k] = higher[k].max(c);
+                sum[k] += c;
+            }
+        }
+
+        let count = positions.len();
+        let centroid = sum
+            .into_iter()
+            .map(|c| c / count as f64)
+            .collect::<Vec<_>>();
+
+        Some(Summary {
+            count,
+            lower: lower.into(),
+            higher: higher.into(),
+            centroid: centroid.into(),
+        })
+    }
 }
 
 /// Index over a single dataset
@@ -115,7 +215,13 @@ impl Properties {
 pub struct Core {
     title: String,
     version: String,
-    properties: Vec<Properties>,
+    properties: Table<Properties>,
+    // Attributes of the identifiers, at the same offsets as `properties`.
+    attributes: Table<Attributes>,
+    // Offsets within `properties`, ordered by identifier. New identifiers
+    // are appended to `properties`, so that the offsets stored in the
+    // indices stay valid.
+    properties_by_id: Vec<usize>,
     space_db: Vec<SpaceDB>,
 }
 
@@ -157,6 +263,11 @@ impl Core {
     ///     The minimum number of elements contained within an index is
     ///     this value or the number of *identifiers*, whichever is
     ///     greater.
+    ///
+    ///  * `threads`:
+    ///     The number of threads to use to build the index, or the
+    ///     available parallelism when `None`. The index is the same
+    ///     whatever the number of threads.
     pub fn new<S>(
         title: S,
         version: S,
@@ -165,12 +276,15 @@ impl Core {
         space_objects: Vec<SpaceSetObject>,
         scales: Option<Vec<Vec<u32>>>,
         max_elements: Option<usize>,
-    ) -> Result<Self, String>
+        threads: Option<usize>,
+    ) -> Result<Self, Error>
     where
         S: Into<String>,
     {
-        // Sort out the space, and create a SpaceDB per reference space
-        let mut space_dbs = vec![];
+        let threads = match threads {
+            Some(threads) => threads.max(1),
+            None => thread::available_parallelism().map_or(1, |n| n.get()),
+        };
 
         // We cannot return less that the total number of individual Ids stored
         // in the index for a full-volume query.
@@ -180,29 +294,253 @@ impl Core {
             None
         };
 
-        for space in spaces {
-            // Filter the points of this space, and encode them before creating the index.
-            let mut filtered = space_objects
+        // Sort out the space, and create a SpaceDB per reference space. Up
+        // to `threads` spaces are indexed concurrently, sharing the threads.
+        let mut space_dbs = vec![];
+
+        for batch in spaces.chunks(threads) {
+            let threads = (threads / batch.len()).max(1);
+
+            let batch = thread::scope(|scope| {
+                batch
+                    .iter()
+                    .map(|space| {
+                        let space_objects = &space_objects;
+                        let scales = scales.clone();
+
+                        scope.spawn(move || {
+                            // Filter the points of this space, and encode them before creating the index.
+                            let mut filtered = space_objects
+                                .iter()
+                                .filter(|object| object.space_id() == space.name())
+                                // Clone only the selected objects, not all of them!
+                                .cloned()
+                                .collect::<Vec<_>>();
+
+                            for object in filtered.iter_mut() {
+                                let position: Vec<f64> = object.position().into();
+                                object.set_position(space.encode(&position)?);
+                            }
+
+                            SpaceDB::new(space, filtered, scales, max_elements, threads)
+                        })
+                    })
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .map(|handle| handle.join().unwrap())
+                    .collect::<Vec<_>>()
+            });
+
+            for space_db in batch {
+                space_dbs.push(space_db?);
+            }
+        }
+
+        let attributes = vec![Attributes::new(); properties.len()];
+
+        Ok(Core::from_parts(
+            title, version, properties, attributes, space_dbs,
+        ))
+    }
+
+    // Assemble a dataset from already built indices. The values stored in
+    // `space_db` are offsets within `properties` and `attributes`.
+    pub(crate) fn from_parts<S>(
+        title: S,
+        version: S,
+        properties: Vec<Properties>,
+        attributes: Vec<Attributes>,
+        space_db: Vec<SpaceDB>,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        let mut properties_by_id = (0..properties.len()).collect::<Vec<_>>();
+        properties_by_id.sort_unstable_by_key(|&offset| properties[offset].key());
+
+        Core {
+            title: title.into(),
+            version: version.into(),
+            properties: properties.into(),
+            attributes: attributes.into(),
+            properties_by_id,
+            space_db,
+        }
+    }
+
+    // Assemble a dataset from memory-mapped tables, whose properties are
+    // already ordered by identifier.
+    pub(crate) fn from_mapped<S>(
+        title: S,
+        version: S,
+        properties: MappedTable,
+        attributes: MappedTable,
+        space_db: Vec<SpaceDB>,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Core {
+            title: title.into(),
+            version: version.into(),
+            properties_by_id: (0..properties.len()).collect(),
+            properties: Table::mapped(properties),
+            attributes: Table::mapped(attributes),
+            space_db,
+        }
+    }
+
+    /// Add spatial objects to the dataset, without rebuilding the index.
+    ///
+    /// New *identifiers* are registered, and the positions covered by
+    /// the shapes are added to every resolution of the index of their
+    /// reference space. Positions
+    /// already linked to an *identifier* are ignored. The attributes of
+    /// the objects are added to those of their *identifier*, replacing
+    /// the previous values.
+    ///
+    /// Nothing is modified if an error is returned.
+    ///
+    /// # Parameters
+    ///
+    ///  * `db`:
+    ///     The database in which the reference spaces used within
+    ///     `objects` are registered, which is used to query the dataset.
+    ///
+    ///  * `objects`:
+    ///     The spatial objects to add to the dataset.
+    pub fn insert(&mut self, db: &DataBase, objects: &[SpatialObject]) -> Result<(), Error> {
+        let mut properties = vec![];
+        let mut properties_hm = HashMap::new();
+        let mut attributes = vec![];
+        let mut spaces: Vec<Arc<Space>> = vec![];
+        let mut space_objects = vec![];
+
+        // Validate and encode everything first, so that we do not leave the
+        // index half-updated on errors.
+        for object in objects {
+            let p = Properties::from(&object.properties);
+            let value = match self.offset(&p)? {
+                Some(offset) => {
+                    // Read the attributes now, as they are updated below.
+                    self.attributes.get(offset)?;
+                    offset
+                }
+                None => *properties_hm.entry(p).or_insert_with_key(|p| {
+                    properties.push(p.clone());
+                    self.properties.len() + properties.len() - 1
+                }),
+            };
+            attributes.push((value, &object.properties.attributes));
+
+            for shape in &object.shapes {
+                let space = match spaces.iter().find(|s| s.name() == &shape.reference_space) {
+                    Some(space) => space.clone(),
+                    None => {
+                        let space = db.space(&shape.reference_space)?;
+                        if space.as_ref() == Space::universe() {
+                            return Err(Error::UnknownSpace(shape.reference_space.clone()));
+                        }
+                        spaces.push(space.clone());
+                        space
+                    }
+                };
+
+                for position in shape.rasterise(&space)? {
+                    space_objects.push(SpaceSetObject::new(space.name(), position, value));
+                }
+            }
+        }
+
+        // Positions within `properties_by_id` of the new identifiers.
+        let mut by_id = Vec::with_capacity(properties.len());
+        for (i, p) in properties.iter().enumerate() {
+            let position = self.partition(|key| key < p.key())?;
+            by_id.push((position, p.key(), self.properties.len() + i));
+        }
+        by_id.sort_unstable();
+
+        // Index the positions of the reference spaces not used yet.
+        let mut space_db = vec![];
+        let mut inserted = vec![];
+        for space in &spaces {
+            let filtered = space_objects
                 .iter()
                 .filter(|object| object.space_id() == space.name())
-                // Clone only the selected objects, not all of them!
                 .cloned()
                 .collect::<Vec<_>>();
 
-            for object in filtered.iter_mut() {
-                let position: Vec<f64> = object.position().into();
-                object.set_position(space.encode(&position)?);
+            if filtered.is_empty() {
+                continue;
             }
 
-            space_dbs.push(SpaceDB::new(&space, filtered, scales.clone(), max_elements))
+            match self.space_db.iter().position(|s| s.name() == space.name()) {
+                Some(i) => inserted.push((i, filtered)),
+                None => space_db.push(SpaceDB::new(space, filtered, None, None, 1)?),
+            }
         }
 
-        Ok(Core {
-            title: title.into(),
-            version: version.into(),
-            properties,
-            space_db: space_dbs,
-        })
+        // Nothing can fail from here on.
+        let mut properties_by_id = Vec::with_capacity(self.properties_by_id.len() + by_id.len());
+        let mut new = by_id.into_iter().peekable();
+        for (i, &offset) in self.properties_by_id.iter().enumerate() {
+            while let Some((_, _, added)) = new.next_if(|(position, _, _)| *position == i) {
+                properties_by_id.push(added);
+            }
+            properties_by_id.push(offset);
+        }
+        properties_by_id.extend(new.map(|(_, _, added)| added));
+        self.properties_by_id = properties_by_id;
+
+        for p in properties {
+            self.properties.push(p);
+            self.attributes.push(Attributes::new());
+        }
+
+        for (offset, attributes) in attributes {
+            self.attributes.get_mut(offset)?.extend(attributes.clone());
+        }
+
+        for (i, filtered) in inserted {
+            self.space_db[i].insert(&filtered);
+        }
+        self.space_db.extend(space_db);
+
+        Ok(())
+    }
+
+    /// Remove an *identifier* and all its positions from the dataset.
+    ///
+    /// Returns `false` if `id` is not registered within the dataset.
+    ///
+    /// # Parameters
+    ///
+    ///  * `id`:
+    ///     Identifier to remove.
+    ///
+    ///  * `type_name`:
+    ///     Kind of the object to remove, or all the kinds sharing this
+    ///     identifier when `None`.
+    pub fn remove<S>(&mut self, id: S, type_name: Option<&str>) -> Result<bool, Error>
+    where
+        S: Into<String>,
+    {
+        let id: String = id.into();
+
+        // The entries in `properties` are kept, as offsets into that list
+        // are stored in the indices, but they can not be looked up anymore.
+        let range = self.lookup(&id, type_name)?;
+        if range.is_empty() {
+            return Ok(false);
+        }
+
+        for offset in self.properties_by_id.drain(range).collect::<Vec<_>>() {
+            for s in &mut self.space_db {
+                s.remove(offset);
+            }
+        }
+
+        Ok(true)
     }
 
     /// Title of the dataset.
@@ -215,9 +553,305 @@ impl Core {
         &self.version
     }
 
-    /// List of *identifiers* contained in this dataset.
-    pub fn keys(&self) -> &Vec<Properties> {
-        &self.properties
+    /// List of *identifiers* contained in this dataset, ordered by
+    /// *id*.
+    ///
+    /// The *identifiers* of memory-mapped datasets are read when they
+    /// are first used, which fails if the file is corrupted.
+    pub fn keys(&self) -> Result<Vec<&Properties>, Error> {
+        self.properties_by_id
+            .iter()
+            .map(|&offset| self.properties.get(offset))
+            .collect()
+    }
+
+    /// Attributes of an *identifier*.
+    ///
+    /// Returns `None` if `properties` is not registered within the
+    /// dataset.
+    ///
+    /// # Parameters
+    ///
+    ///  * `properties`:
+    ///     Identifier for which to retrieve the attributes.
+    pub fn attributes(&self, properties: &Properties) -> Result<Option<&Attributes>, Error> {
+        match self.offset(properties)? {
+            None => Ok(None),
+            Some(offset) => Ok(Some(self.attributes.get(offset)?)),
+        }
+    }
+
+    /// Replace the attributes of an *identifier*.
+    ///
+    /// Returns `false` if `properties` is not registered within the
+    /// dataset.
+    ///
+    /// # Parameters
+    ///
+    ///  * `properties`:
+    ///     Identifier for which to set the attributes.
+    ///
+    ///  * `attributes`:
+    ///     The new attributes of `properties`.
+    pub fn set_attributes(
+        &mut self,
+        properties: &Properties,
+        attributes: Attributes,
+    ) -> Result<bool, Error> {
+        match self.offset(properties)? {
+            None => Ok(false),
+            Some(offset) => {
+                self.attributes.set(offset, attributes);
+                Ok(true)
+            }
+        }
+    }
+
+    // Check if the attributes of the identifier stored at `offset` satisfy
+    // all the filters of the query.
+    fn is_selected(&self, offset: usize, parameters: &CoreQueryParameters) -> Result<bool, Error> {
+        if parameters.filters.is_empty() {
+            return Ok(true);
+        }
+
+        let attributes = self.attributes.get(offset)?;
+        Ok(parameters
+            .filters
+            .iter()
+            .all(|predicate| predicate.matches(attributes)))
+    }
+
+    // Positions of the identifiers selected by the filters of the query,
+    // with their properties.
+    fn selected(
+        &self,
+        r: Vec<(Position, usize)>,
+        parameters: &CoreQueryParameters,
+    ) -> Result<Vec<(Position, &Properties)>, Error> {
+        let mut selected = Vec::with_capacity(r.len());
+        for (position, value) in r {
+            if self.is_selected(value, parameters)? {
+                selected.push((position, self.properties.get(value)?));
+            }
+        }
+
+        Ok(selected)
+    }
+
+    // Offsets within `properties` of the identifiers still registered,
+    // ordered by identifier.
+    pub(crate) fn offsets(&self) -> &Vec<usize> {
+        &self.properties_by_id
+    }
+
+    pub(crate) fn space_db(&self) -> &Vec<SpaceDB> {
+        &self.space_db
+    }
+
+    // Index of the first entry of `properties_by_id` whose key is not
+    // `before` the searched key, which they are ordered by.
+    fn partition<F>(&self, before: F) -> Result<usize, Error>
+    where
+        F: Fn((&str, &str)) -> bool,
+    {
+        let by_id = &self.properties_by_id;
+        let (mut low, mut high) = (0, by_id.len());
+
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if before(self.properties.get(by_id[middle])?.key()) {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+
+        Ok(low)
+    }
+
+    // Range within `properties_by_id` of the identifiers `id`, of kind
+    // `type_name`, or of any kind when `None`.
+    fn lookup(&self, id: &str, type_name: Option<&str>) -> Result<Range<usize>, Error> {
+        Ok(match type_name {
+            None => self.partition(|key| key.0 < id)?..self.partition(|key| key.0 <= id)?,
+            Some(type_name) => {
+                self.partition(|key| key < (id, type_name))?
+                    ..self.partition(|key| key <= (id, type_name))?
+            }
+        })
+    }
+
+    // Offset within `properties` of the identifier, if registered.
+    fn offset(&self, properties: &Properties) -> Result<Option<usize>, Error> {
+        let range = self.lookup(properties.id(), Some(properties.type_name()))?;
+
+        Ok(self.properties_by_id[range].first().cloned())
+    }
+
+    // Offsets within `properties` of the identifiers `id`, of kind
+    // `type_name`, or of any kind when `None`.
+    fn offsets_of(&self, id: &str, type_name: Option<&str>) -> Result<&[usize], Error> {
+        Ok(&self.properties_by_id[self.lookup(id, type_name)?])
+    }
+
+    // Every selected position of the core, with the properties of its
+    // identifier, as decoded coordinates in the space `output`, and as a
+    // position of the Universe. Positions outside of `output` are
+    // skipped, and below the full resolution, positions are approximated
+    // by the center of their cell.
+    pub(crate) fn decoded_positions(
+        &self,
+        parameters: &CoreQueryParameters,
+        output: &Space,
+    ) -> Result<DecodedPositions, Error> {
+        let db = parameters.db;
+
+        let mut results = vec![];
+        for s in &self.space_db {
+            let space = db.space(s.name())?;
+            let scale = s.resolutions()[s.resolution(parameters)].scale()[0];
+
+            let (low, high) = space.bounding_box();
+            let whole = Shape::BoundingBox(
+                space
+                    .encode(&Vec::<f64>::from(low))?
+                    .reduce_precision(scale),
+                space
+                    .encode(&Vec::<f64>::from(high))?
+                    .reduce_precision(scale),
+            );
+
+            let r = match s.get_by_shape(&whole, parameters) {
+                Ok(r) => r,
+                // The view port does not intersect this space.
+                Err(Error::OutOfBounds(_)) => continue,
+                Err(e) => return Err(e),
+            };
+
+            for (position, value) in r {
+                if !self.is_selected(value, parameters)? {
+                    continue;
+                }
+
+                let position = Self::cell_center(&space, &position, scale);
+                if let Some(rebased) = Space::change_base_within(&position, &space, output)? {
+                    let absolute = Space::change_base(&position, &space, Space::universe())?;
+                    results.push((
+                        self.properties.get(value)?,
+                        output.decode(&rebased)?,
+                        absolute,
+                    ));
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    // Selected positions of the core around `origin`, with the properties
+    // of their identifier, as decoded coordinates in the space `output`.
+    // Positions outside of `output` are skipped.
+    //
+    // Every position within `radius` of `origin`, both expressed in the
+    // Universe, is returned, but positions slightly further away may be
+    // as well. Below the full resolution, positions are approximated by
+    // the center of their cell.
+    pub(crate) fn decoded_positions_around(
+        &self,
+        parameters: &CoreQueryParameters,
+        origin: &Position,
+        radius: f64,
+        output: &Space,
+    ) -> Result<Vec<(&Properties, Vec<f64>)>, Error> {
+        let db = parameters.db;
+
+        let mut results = vec![];
+        for s in &self.space_db {
+            let space = db.space(s.name())?;
+            let center = Space::change_base(origin, Space::universe(), &space)?;
+            let scale = s.resolutions()[s.resolution(parameters)].scale()[0];
+            let (shape, _) = Self::search_box(&space, &center, radius, scale);
+
+            let r = match s.get_by_shape(&shape, parameters) {
+                Ok(r) => r,
+                // The view port does not intersect the search volume.
+                Err(Error::OutOfBounds(_)) => continue,
+                Err(e) => return Err(e),
+            };
+
+            for (position, value) in r {
+                if !self.is_selected(value, parameters)? {
+                    continue;
+                }
+
+                let position = Self::cell_center(&space, &position, scale);
+                if let Some(rebased) = Space::change_base_within(&position, &space, output)? {
+                    results.push((self.properties.get(value)?, output.decode(&rebased)?));
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    // Cells of `space`, with `shift` bits of precision removed, covered by
+    // the selected identifiers of the core, at the resolution selected by
+    // the parameters. Each cell of the index is mapped through its center.
+    pub(crate) fn cells(
+        &self,
+        parameters: &CoreQueryParameters,
+        space: &Space,
+        shift: u32,
+    ) -> Result<HashMap<Position, Vec<&Properties>>, Error> {
+        let db = parameters.db;
+
+        let mut cells = HashMap::new();
+        for s in &self.space_db {
+            let from = db.space(s.name())?;
+            let view_port = parameters.view_port(&from);
+
+            let resolution = s.resolution(parameters);
+            let scale = s.resolutions()[resolution].scale()[0];
+
+            // Offset of the center of a cell of the index, and last valid
+            // coordinate, in steps of the full resolution.
+            let half = (1u64 << scale) as f64 / 2.0;
+            let last = from
+                .axes()
+                .iter()
+                .map(|a| a.graduation().steps as f64)
+                .collect::<Vec<_>>();
+
+            for (position, value) in s.objects(&from, resolution)? {
+                if !self.is_selected(value, parameters)? {
+                    continue;
+                }
+
+                let corner: Position = (0..position.dimensions())
+                    .map(|k| position[k].u64() << scale)
+                    .collect::<Vec<_>>()
+                    .into();
+                if let Some(view_port) = &view_port {
+                    if !view_port.contains(&corner) {
+                        continue;
+                    }
+                }
+
+                let center: Position = (0..corner.dimensions())
+                    .map(|k| (corner[k].f64() + half).min(last[k]))
+                    .collect::<Vec<_>>()
+                    .into();
+                let cell = Space::change_base(&center, &from, space)?.reduce_precision(shift);
+
+                let properties = self.properties.get(value)?;
+                let values = cells.entry(cell).or_insert_with(Vec::new);
+                if !values.contains(&properties) {
+                    values.push(properties);
+                }
+            }
+        }
+
+        Ok(cells)
     }
 
     fn decode_positions(
@@ -225,14 +859,14 @@ impl Core {
         space: &Space,
         db: &DataBase,
         output_space: &Option<&str>,
-    ) -> Result<(), String> {
+    ) -> Result<(), Error> {
         if let Some(unified_id) = *output_space {
             let unified = db.space(unified_id)?;
 
             // Rebase the point to the requested output space before decoding.
             for (position, _) in list {
                 *position = unified
-                    .decode(&Space::change_base(&position, space, unified)?)?
+                    .decode(&Space::change_base(&position, space, &unified)?)?
                     .into();
             }
         } else {
@@ -268,38 +902,74 @@ impl Core {
         positions: &[Position],
         space_id: &str,
     ) -> ResultSet {
-        let CoreQueryParameters {
-            db, output_space, ..
-        } = parameters;
-
-        let mut results = vec![];
-        let count = positions.len();
-        let from = db.space(space_id)?;
-
-        // Filter positions based on the view port, if present
-        let filtered = match parameters.view_port(from) {
-            None => positions.iter().map(|p| p).collect::<Vec<_>>(),
-            Some(view_port) => positions
-                .iter()
-                .filter(|&p| view_port.contains(p))
-                .collect::<Vec<_>>(),
-        };
+        self.get_by_positions_iter(parameters, positions.iter().cloned(), space_id)
+    }
 
-        for s in &self.space_db {
+    /// Retrieve everything located at specific positions, provided by
+    /// an iterator.
+    ///
+    /// The positions are consumed one at a time, once per space of the
+    /// core, so that large volumes, for example from
+    /// [`rasterise_iter`][rasterise], do not have to be kept in memory.
+    ///
+    /// # Parameters
+    ///
+    ///  * `parameters`:
+    ///     Search parameters, see [CoreQueryParameters](struct.CoreQueryParameters.html).
+    ///
+    ///  * `positions`:
+    ///     Volume to use to filter data points.
+    ///
+    ///  * `space_id`:
+    ///     *positions* are defined as decoded coordinates in this
+    ///     reference space.
+    ///
+    /// [rasterise]: space/enum.Shape.html#method.rasterise_iter
+    pub fn get_by_positions_iter<I>(
+        &self,
+        parameters: &CoreQueryParameters,
+        positions: I,
+        space_id: &str,
+    ) -> ResultSet
+    where
+        I: Iterator<Item = Position> + Clone,
+    {
+        let CoreQueryParameters {
+            db, output_space, ..
+        } = parameters;
+
+        let mut results = vec![];
+        let from = db.space(space_id)?;
+        let view_port = parameters.view_port(&from);
+
+        for s in &self.space_db {
             let to = db.space(s.name())?;
-            let mut p = Vec::with_capacity(count);
 
-            for position in filtered.as_slice() {
-                let position: Vec<f64> = Space::change_base(position, from, to)?.into();
-                p.push(to.encode(&position)?);
+            // Filter positions based on the view port, if present, and
+            // convert them on the fly, stopping at the first error.
+            let mut error = None;
+            let p = positions
+                .clone()
+                .filter(|p| match &view_port {
+                    None => true,
+                    Some(view_port) => view_port.contains(p),
+                })
+                .map(|position| Space::change_base(&position, &from, &to))
+                .scan(&mut error, |error, position| match position {
+                    Ok(position) => Some(position),
+                    Err(e) => {
+                        **error = Some(e);
+                        None
+                    }
+                });
+
+            let r = s.get_by_positions_iter(p, parameters)?;
+            if let Some(e) = error {
+                return Err(e);
             }
 
-            let mut r = s
-                .get_by_positions(&p, parameters)?
-                .into_iter()
-                .map(|(position, fields)| (position, &self.properties[fields.value()]))
-                .collect::<Vec<_>>();
-            Self::decode_positions(r.as_mut_slice(), to, db, output_space)?;
+            let mut r = self.selected(r, parameters)?;
+            Self::decode_positions(r.as_mut_slice(), &to, db, output_space)?;
 
             results.push((s.name(), r));
         }
@@ -307,6 +977,37 @@ impl Core {
         Ok(results)
     }
 
+    // Positions, in encoded coordinates, and offsets of the selected
+    // identifiers within `shape`, for each space of `space_db`, in order.
+    fn select_by_shape(
+        &self,
+        parameters: &CoreQueryParameters,
+        shape: &Shape,
+        space_id: &str,
+    ) -> Result<Vec<Vec<(Position, usize)>>, Error> {
+        let db = parameters.db;
+
+        let mut results = vec![];
+        let shape_space = db.space(space_id)?;
+
+        for s in &self.space_db {
+            let current_space = db.space(s.name())?;
+
+            let current_shape = shape.rebase(&shape_space, &current_space)?;
+
+            let mut r = vec![];
+            for (position, value) in s.get_by_shape(&current_shape, parameters)? {
+                if self.is_selected(value, parameters)? {
+                    r.push((position, value));
+                }
+            }
+
+            results.push(r);
+        }
+
+        Ok(results)
+    }
+
     /// Search using a [shape] which defines a volume.
     ///
     /// # Parameters
@@ -333,24 +1034,381 @@ impl Core {
         } = parameters;
 
         let mut results = vec![];
-        let shape_space = db.space(space_id)?;
+        let selected = self.select_by_shape(parameters, shape, space_id)?;
 
-        for s in &self.space_db {
+        for (s, r) in self.space_db.iter().zip(selected) {
             let current_space = db.space(s.name())?;
 
-            let current_shape = shape.rebase(shape_space, current_space)?;
-            //            println!("current shape: {:?}", current_shape);
-            //            let current_shape = shape.encode(current_space)?;
-            //            println!("current shape Encoded: {:?}", current_shape);
+            let mut r = r
+                .into_iter()
+                .map(|(position, value)| Ok((position, self.properties.get(value)?)))
+                .collect::<Result<Vec<_>, Error>>()?;
+            Self::decode_positions(r.as_mut_slice(), &current_space, db, output_space)?;
+
+            results.push((s.name(), r));
+        }
+
+        Ok(results)
+    }
+
+    /// Count the positions within a [shape], without retrieving them.
+    ///
+    /// This is the total number of results
+    /// [get_by_shape](#method.get_by_shape) would return.
+    ///
+    /// # Parameters
+    ///
+    ///  * `parameters`:
+    ///     Search parameters, see [CoreQueryParameters](struct.CoreQueryParameters.html).
+    ///
+    ///  * `shape`:
+    ///     Volume to use to filter data points.
+    ///
+    ///  * `space_id`:
+    ///     *shape* is defined as decoded coordinates in this
+    ///     reference space.
+    ///
+    /// [shape]: space/enum.Shape.html
+    pub fn count_by_shape(
+        &self,
+        parameters: &CoreQueryParameters,
+        shape: &Shape,
+        space_id: &str,
+    ) -> Result<usize, Error> {
+        Ok(self
+            .select_by_shape(parameters, shape, space_id)?
+            .iter()
+            .map(Vec::len)
+            .sum())
+    }
+
+    /// Count the positions within a [shape] of each *identifier*,
+    /// without retrieving them.
+    ///
+    /// Identifiers without any position within the shape are omitted,
+    /// and the counts are ordered by identifier.
+    ///
+    /// # Parameters
+    ///
+    ///  * `parameters`:
+    ///     Search parameters, see [CoreQueryParameters](struct.CoreQueryParameters.html).
+    ///
+    ///  * `shape`:
+    ///     Volume to use to filter data points.
+    ///
+    ///  * `space_id`:
+    ///     *shape* is defined as decoded coordinates in this
+    ///     reference space.
+    ///
+    /// [shape]: space/enum.Shape.html
+    pub fn count_by_shape_per_id(
+        &self,
+        parameters: &CoreQueryParameters,
+        shape: &Shape,
+        space_id: &str,
+    ) -> Result<Vec<(&Properties, usize)>, Error> {
+        let mut counts = HashMap::new();
+        for r in self.select_by_shape(parameters, shape, space_id)? {
+            for (_, value) in r {
+                *counts.entry(value).or_insert(0) += 1;
+            }
+        }
+
+        let mut counts = counts
+            .into_iter()
+            .map(|(value, count)| Ok((self.properties.get(value)?, count)))
+            .collect::<Result<Vec<_>, Error>>()?;
+        counts.sort_unstable_by(|(a, _), (b, _)| a.key().cmp(&b.key()));
+
+        Ok(counts)
+    }
+
+    /// Count the positions within a [shape], binned on the cells of the
+    /// index resolution selected by the parameters.
+    ///
+    /// The bins are returned per reference space, ordered, each one
+    /// located at its lowest corner and converted like the results of
+    /// [get_by_shape](#method.get_by_shape). Empty bins are omitted.
+    ///
+    /// Below the full resolution, the index keeps a single position per
+    /// cell for each *identifier*, so the bins count the identifiers
+    /// present in each cell, as [get_by_shape](#method.get_by_shape)
+    /// would return them.
+    ///
+    /// # Parameters
+    ///
+    ///  * `parameters`:
+    ///     Search parameters, see [CoreQueryParameters](struct.CoreQueryParameters.html).
+    ///
+    ///  * `shape`:
+    ///     Volume to use to filter data points.
+    ///
+    ///  * `space_id`:
+    ///     *shape* is defined as decoded coordinates in this
+    ///     reference space.
+    ///
+    /// [shape]: space/enum.Shape.html
+    pub fn histogram_by_shape(
+        &self,
+        parameters: &CoreQueryParameters,
+        shape: &Shape,
+        space_id: &str,
+    ) -> Histogram {
+        let CoreQueryParameters {
+            db, output_space, ..
+        } = parameters;
+
+        let mut results = vec![];
+        let selected = self.select_by_shape(parameters, shape, space_id)?;
+
+        for (s, r) in self.space_db.iter().zip(selected) {
+            let current_space = db.space(s.name())?;
+            let scale = s.resolutions()[s.resolution(parameters)].scale()[0];
+
+            let mut bins = HashMap::new();
+            for (position, _) in r {
+                *bins.entry(position).or_insert(0) += 1;
+            }
+
+            let mut bins = bins.into_iter().collect::<Vec<_>>();
+            // Use the total order, as positions are only partially ordered.
+            bins.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+            for (position, _) in &mut bins {
+                // Back to the full resolution coordinates of the space.
+                let corner: Position = (0..position.dimensions())
+                    .map(|k| position[k].u64() << scale)
+                    .collect::<Vec<_>>()
+                    .into();
+
+                *position = if let Some(unified_id) = *output_space {
+                    let unified = db.space(unified_id)?;
+
+                    // Rebase the point to the requested output space before decoding.
+                    unified
+                        .decode(&Space::change_base(&corner, &current_space, &unified)?)?
+                        .into()
+                } else {
+                    current_space.decode(&corner)?.into()
+                };
+            }
 
-            let mut r = s
-                .get_by_shape(&current_shape, parameters)?
+            results.push((s.name(), bins));
+        }
+
+        Ok(results)
+    }
+
+    // Euclidean distance between two positions in the Universe.
+    fn distance(a: &Position, b: &Position) -> f64 {
+        let a: Vec<f64> = a.into();
+        let b: Vec<f64> = b.into();
+
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    // Center of the cell at `position`, in an index of `space` storing
+    // positions with `scale` bits of precision removed, as encoded
+    // coordinates of the full resolution of `space`.
+    fn cell_center(space: &Space, position: &Position, scale: u32) -> Position {
+        if scale == 0 {
+            return position.clone();
+        }
+
+        let half = ((1u64 << scale) - 1) as f64 / 2.0;
+        let axes = space.axes();
+
+        (0..position.dimensions())
+            .map(|k| {
+                let corner = (position[k].u64() << scale) as f64;
+                (corner + half).min(axes[k].graduation().steps as f64)
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    // Box of encoded coordinates of `space` containing every position
+    // within `radius`, in the Universe, of `center`, and whether it
+    // covers the whole space. `center` is expressed in encoded
+    // coordinates of `space`, and the box in those of an index with
+    // `scale` bits of precision removed.
+    fn search_box(space: &Space, center: &Position, radius: f64, scale: u32) -> (Shape, bool) {
+        let axes = space.axes();
+
+        let mut lower = Vec::with_capacity(axes.len());
+        let mut higher = Vec::with_capacity(axes.len());
+        let mut whole = true;
+
+        for k in 0..axes.len() {
+            // Coordinates are projections on the axes, so the box reaches
+            // `radius` along each of them, with one more step to account
+            // for rounding.
+            let step = axes[k].project_out_vector(1.0).norm();
+            let reach = (radius / step).ceil() as u64 + 1;
+            let last = axes[k].graduation().steps;
+            let c = center[k].u64();
+
+            lower.push(c.saturating_sub(reach));
+            higher.push(c.saturating_add(reach).min(last));
+            whole &= lower[k] == 0 && higher[k] == last;
+        }
+
+        let lower: Position = lower.into();
+        let higher: Position = higher.into();
+
+        (
+            Shape::BoundingBox(
+                lower.reduce_precision(scale),
+                higher.reduce_precision(scale),
+            ),
+            whole,
+        )
+    }
+
+    // Search the `k` identifiers closest to `origin`, in a single space,
+    // with their closest position, in encoded coordinates, and its
+    // distance. Below the full resolution, positions are approximated by
+    // the center of their cell.
+    //
+    // `origin` is expressed in Universe coordinates, and `center` is its
+    // projection into `space`, from which the search volume grows.
+    fn nearest_in(
+        &self,
+        parameters: &CoreQueryParameters,
+        s: &SpaceDB,
+        space: &Space,
+        origin: &Position,
+        center: &Position,
+        k: usize,
+    ) -> Result<Vec<(Position, usize, f64)>, Error> {
+        let mut radius = space
+            .axes()
+            .iter()
+            .map(|a| a.project_out_vector(1.0).norm())
+            .fold(f64::INFINITY, f64::min);
+        let scale = s.resolutions()[s.resolution(parameters)].scale()[0];
+
+        loop {
+            let (shape, whole) = Self::search_box(space, center, radius, scale);
+
+            // Keep the closest position of each identifier.
+            let mut found = HashMap::new();
+            match s.get_by_shape(&shape, parameters) {
+                Ok(r) => {
+                    for (position, value) in r {
+                        if !self.is_selected(value, parameters)? {
+                            continue;
+                        }
+
+                        let position = Self::cell_center(space, &position, scale);
+                        let absolute = Space::change_base(&position, space, Space::universe())?;
+                        let distance = Self::distance(origin, &absolute);
+                        let closest = found
+                            .entry(value)
+                            .or_insert_with(|| (position.clone(), distance));
+                        if distance < closest.1 {
+                            *closest = (position, distance);
+                        }
+                    }
+                }
+                // The search volume does not reach the view port yet.
+                Err(Error::OutOfBounds(_)) => (),
+                Err(e) => return Err(e),
+            }
+
+            let mut found = found
                 .into_iter()
-                .map(|(position, fields)| (position, &self.properties[fields.value()]))
+                .map(|(value, (position, distance))| (position, value, distance))
                 .collect::<Vec<_>>();
-            Self::decode_positions(r.as_mut_slice(), current_space, db, output_space)?;
+            found.sort_unstable_by(|a, b| a.2.total_cmp(&b.2).then(a.1.cmp(&b.1)));
 
-            results.push((s.name(), r));
+            // Anything not found yet is further than `radius`.
+            if whole || (found.len() >= k && found[k - 1].2 <= radius) {
+                found.truncate(k);
+                return Ok(found);
+            }
+
+            radius *= 2.0;
+        }
+    }
+
+    /// Search for the `k` *identifiers* closest to a position.
+    ///
+    /// Each identifier is returned once, at its closest position, along
+    /// with the reference space of that position and their distance.
+    /// Distances are measured in the Universe, so that results from
+    /// every reference space can be compared, and the results are
+    /// sorted by increasing distance.
+    ///
+    /// # Parameters
+    ///
+    ///  * `parameters`:
+    ///     Search parameters, see [CoreQueryParameters](struct.CoreQueryParameters.html).
+    ///
+    ///  * `position`:
+    ///     Position from which distances are measured.
+    ///
+    ///  * `k`:
+    ///     Maximum number of identifiers to return.
+    ///
+    ///  * `space_id`:
+    ///     *position* is defined as decoded coordinates in this
+    ///     reference space.
+    ///
+    pub fn get_nearest(
+        &self,
+        parameters: &CoreQueryParameters,
+        position: &Position,
+        k: usize,
+        space_id: &str,
+    ) -> Result<Vec<(&String, Position, &Properties, f64)>, Error> {
+        let CoreQueryParameters {
+            db, output_space, ..
+        } = parameters;
+
+        let from = db.space(space_id)?;
+        let position: Vec<f64> = position.into();
+        let position = from.encode(&position)?;
+        let origin = Space::change_base(&position, &from, Space::universe())?;
+
+        // Closest position of each identifier, over all the spaces.
+        let mut nearest: HashMap<usize, (usize, Position, f64)> = HashMap::new();
+        if k > 0 {
+            for (i, s) in self.space_db.iter().enumerate() {
+                let to = db.space(s.name())?;
+                let center = Space::change_base(&position, &from, &to)?;
+
+                for (position, value, distance) in
+                    self.nearest_in(parameters, s, &to, &origin, &center, k)?
+                {
+                    let closest = nearest
+                        .entry(value)
+                        .or_insert_with(|| (i, position.clone(), distance));
+                    if distance < closest.2 {
+                        *closest = (i, position, distance);
+                    }
+                }
+            }
+        }
+
+        let mut nearest = nearest.into_iter().collect::<Vec<_>>();
+        nearest
+            .sort_unstable_by(|(a, (_, _, da)), (b, (_, _, db))| da.total_cmp(db).then(a.cmp(b)));
+        nearest.truncate(k);
+
+        let mut results = Vec::with_capacity(nearest.len());
+        for (value, (i, position, distance)) in nearest {
+            let s = &self.space_db[i];
+            let mut r = [(position, self.properties.get(value)?)];
+            let space = db.space(s.name())?;
+            Self::decode_positions(&mut r, &space, db, output_space)?;
+
+            let [(position, properties)] = r;
+            results.push((s.name(), position, properties, distance));
         }
 
         Ok(results)
@@ -366,54 +1424,151 @@ impl Core {
     ///  * `id`:
     ///     Identifier for which to retrieve is positions.
     ///
+    ///  * `type_name`:
+    ///     Kind of the object, or all the kinds sharing this identifier
+    ///     when `None`.
+    ///
     pub fn get_by_id<S>(
         &self,
         parameters: &CoreQueryParameters,
         id: S,
-    ) -> Result<Vec<(&String, Vec<Position>)>, String>
+        type_name: Option<&str>,
+    ) -> Result<Vec<(&String, Vec<Position>)>, Error>
     where
         S: Into<String>,
     {
+        let id: String = id.into();
+
+        // Do we have this ID registered at all, with matching attributes?
+        let mut offsets = vec![];
+        for &offset in self.offsets_of(&id, type_name)? {
+            if self.is_selected(offset, parameters)? {
+                offsets.push(offset);
+            }
+        }
+
+        if offsets.is_empty() {
+            Ok(vec![])
+        } else {
+            // Yes, so now let's find all the position linked to it, per
+            // reference space
+            self.positions_of(parameters, &offsets)
+        }
+    }
+
+    // Positions of the identifiers stored at `offsets`, per reference
+    // space, decoded as requested by the parameters.
+    fn positions_of(
+        &self,
+        parameters: &CoreQueryParameters,
+        offsets: &[usize],
+    ) -> Result<Vec<(&String, Vec<Position>)>, Error> {
         let CoreQueryParameters {
             db, output_space, ..
         } = parameters;
 
-        let id: String = id.into();
         let mut results = vec![];
 
-        // Do we have this ID registered at all?
-        if let Ok(offset) = self
-            .properties
-            .binary_search_by_key(&id.as_str(), |properties| properties.id())
-        {
-            // Yes, so now let's find all the position linked to it, per
-            // reference space
-            for s in &self.space_db {
-                let current_space = db.space(s.name())?;
+        for s in &self.space_db {
+            let current_space = db.space(s.name())?;
 
-                let mut positions = s.get_by_id(offset, parameters)?;
+            let mut positions = vec![];
+            for &offset in offsets {
+                positions.append(&mut s.get_by_id(offset, parameters)?);
+            }
 
-                //Self::decode_positions(r.as_mut_slice(), current_space, db, output_space)?;
-                if let Some(unified_id) = *output_space {
-                    let unified = db.space(unified_id)?;
+            //Self::decode_positions(r.as_mut_slice(), current_space, db, output_space)?;
+            if let Some(unified_id) = *output_space {
+                let unified = db.space(unified_id)?;
 
-                    // Rebase the point to the requested output space before decoding.
-                    for position in &mut positions {
-                        *position = unified
-                            .decode(&Space::change_base(position, current_space, unified)?)?
-                            .into();
-                    }
-                } else {
-                    // Decode the positions into f64 values, which are defined in their
-                    // respective reference space.
-                    for position in &mut positions {
-                        // Simply decode
-                        *position = current_space.decode(position)?.into();
-                    }
+                // Rebase the point to the requested output space before decoding.
+                for position in &mut positions {
+                    *position = unified
+                        .decode(&Space::change_base(position, &current_space, &unified)?)?
+                        .into();
+                }
+            } else {
+                // Decode the positions into f64 values, which are defined in their
+                // respective reference space.
+                for position in &mut positions {
+                    // Simply decode
+                    *position = current_space.decode(position)?.into();
                 }
-
-                results.push((s.name(), positions));
             }
+
+            results.push((s.name(), positions));
+        }
+
+        Ok(results)
+    }
+
+    /// Summarise the positions linked to an id, per reference space.
+    ///
+    /// The positions are retrieved as with [get_by_id](#method.get_by_id),
+    /// so the summaries are expressed in the same coordinates. Reference
+    /// spaces without any position are omitted.
+    ///
+    /// # Parameters
+    ///
+    ///  * `parameters`:
+    ///     Search parameters, see [CoreQueryParameters](struct.CoreQueryParameters.html).
+    ///
+    ///  * `id`:
+    ///     Identifier for which to summarise the positions.
+    ///
+    ///  * `type_name`:
+    ///     Kind of the object, or all the kinds sharing this identifier
+    ///     when `None`.
+    ///
+    pub fn summary<S>(
+        &self,
+        parameters: &CoreQueryParameters,
+        id: S,
+        type_name: Option<&str>,
+    ) -> Result<Vec<(&String, Summary)>, Error>
+    where
+        S: Into<String>,
+    {
+        Ok(self
+            .get_by_id(parameters, id, type_name)?
+            .into_iter()
+            .filter_map(|(space, positions)| Some((space, Summary::new(&positions)?)))
+            .collect())
+    }
+
+    /// Summarise the positions linked to several ids, per reference
+    /// space.
+    ///
+    /// The summaries are returned in the order of `ids`, see
+    /// [summary](#method.summary). Unknown identifiers, or those not
+    /// matching the filters, have no summaries.
+    ///
+    /// # Parameters
+    ///
+    ///  * `parameters`:
+    ///     Search parameters, see [CoreQueryParameters](struct.CoreQueryParameters.html).
+    ///
+    ///  * `ids`:
+    ///     Identifiers for which to summarise the positions.
+    ///
+    pub fn summaries(
+        &self,
+        parameters: &CoreQueryParameters,
+        ids: &[Properties],
+    ) -> Result<Vec<Vec<(&String, Summary)>>, Error> {
+        let mut results = Vec::with_capacity(ids.len());
+
+        for properties in ids {
+            let summaries = match self.offset(properties)? {
+                Some(offset) if self.is_selected(offset, parameters)? => self
+                    .positions_of(parameters, &[offset])?
+                    .into_iter()
+                    .filter_map(|(space, positions)| Some((space, Summary::new(&positions)?)))
+                    .collect(),
+                _ => vec![],
+            };
+
+            results.push(summaries);
         }
 
         Ok(results)
@@ -430,7 +1585,16 @@ impl Core {
     ///  * `id`:
     ///     Identifier to use to define the search volume.
     ///
-    pub fn get_by_label<S>(&self, parameters: &CoreQueryParameters, id: S) -> ResultSet
+    ///  * `type_name`:
+    ///     Kind of the object, or all the kinds sharing this identifier
+    ///     when `None`.
+    ///
+    pub fn get_by_label<S>(
+        &self,
+        parameters: &CoreQueryParameters,
+        id: S,
+        type_name: Option<&str>,
+    ) -> ResultSet
     where
         S: Into<String>,
     {
@@ -444,17 +1608,17 @@ impl Core {
         // Convert the view port to the encoded space coordinates
         let view_port = parameters.view_port(Space::universe());
 
-        if let Ok(offset) = self
-            .properties
-            .binary_search_by_key(&id.as_str(), |properties| properties.id())
-        {
+        let offsets = self.offsets_of(&id, type_name)?;
+
+        if !offsets.is_empty() {
             // Generate the search volume. Iterate over all reference spaces, to
             // retrieve a list of SpaceSetObjects linked to `id`, then iterate
             // over the result to generate a list of positions in Universe.
             let search_volume = self
                 .space_db
                 .iter()
-                .filter_map(|s| {
+                .flat_map(|s| offsets.iter().map(move |&offset| (s, offset)))
+                .filter_map(|(s, offset)| {
                     match db.space(s.name()) {
                         Err(_) => None,
                         Ok(from) => match s.get_by_id(offset, parameters) {
@@ -464,7 +1628,7 @@ impl Core {
                                 let mut p = vec![];
                                 for position in v {
                                     if let Ok(position) =
-                                        Space::change_base(&position, from, Space::universe())
+                                        Space::change_base(&position, &from, Space::universe())
                                     {
                                         p.push(position)
                                     }
@@ -492,23 +1656,18 @@ impl Core {
 
                 // Convert the search Volume into the target space.
                 for position in &search_volume {
-                    let position = Space::change_base(position, Space::universe(), to)?;
+                    let position = Space::change_base(position, Space::universe(), &to)?;
                     p.push(position);
                 }
 
-                let mut r = s
+                let r = s
                     .get_by_positions(&p, parameters)?
                     .into_iter()
-                    .filter_map(|(position, fields)| {
-                        if fields.value() == offset {
-                            None
-                        } else {
-                            Some((position, &self.properties[fields.value()]))
-                        }
-                    })
+                    .filter(|(_, value)| !offsets.contains(value))
                     .collect::<Vec<_>>();
+                let mut r = self.selected(r, parameters)?;
 
-                Self::decode_positions(r.as_mut_slice(), to, db, output_space)?;
+                Self::decode_positions(r.as_mut_slice(), &to, db, output_space)?;
 
                 results.push((s.name(), r));
             }
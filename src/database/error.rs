@@ -0,0 +1,57 @@
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+/// Errors reported by the database and storage functions.
+#[derive(Debug)]
+pub enum Error {
+    /// No reference space is registered under this name.
+    UnknownSpace(String),
+
+    /// No dataset (core) is registered under this name.
+    UnknownCore(String),
+
+    /// A value is outside of the valid range, for example of an axis
+    /// or of the view port.
+    OutOfBounds(String),
+
+    /// The number of dimensions is not the expected one, stored as
+    /// (`expected`, `actual`) tuples.
+    DimensionMismatch(usize, usize),
+
+    /// A definition, such as an axis, a shape or index parameters, is
+    /// invalid.
+    InvalidDefinition(String),
+
+    /// A name is already in use, with a different definition.
+    Conflict(String),
+
+    /// Persistent data could not be read or written.
+    Storage(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Error::UnknownSpace(name) => write!(f, "No spaces registered under `{}`", name),
+            Error::UnknownCore(name) => write!(f, "No cores registered under `{}`", name),
+            Error::OutOfBounds(reason) => write!(f, "Out of bounds: {}", reason),
+            Error::DimensionMismatch(expected, actual) => write!(
+                f,
+                "Dimension mismatch: expected {}, got {}",
+                expected, actual
+            ),
+            Error::InvalidDefinition(reason) => write!(f, "Invalid definition: {}", reason),
+            Error::Conflict(reason) => write!(f, "Conflict: {}", reason),
+            Error::Storage(reason) => write!(f, "Storage error: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Storage(e.to_string())
+    }
+}
@@ -0,0 +1,483 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fs::File;
+use std::io::BufWriter;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::sync::Arc;
+
+use bincode::Options;
+use memmap::Mmap;
+use memmap::MmapOptions;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::space::Position;
+use super::Error;
+
+// Size in bytes of each value stored in a section.
+const WORD: usize = 8;
+
+// Compare two positions along the Z-order (Morton) curve, without
+// computing the interleaved codes.
+//
+// The most significant bit which differs between the two positions
+// decides the order, so we only need to find the dimension it belongs to.
+pub fn morton_cmp<F, G>(dimensions: usize, a: F, b: G) -> Ordering
+where
+    F: Fn(usize) -> u64,
+    G: Fn(usize) -> u64,
+{
+    let mut dimension = 0;
+    let mut msb = 0u64;
+
+    for k in 0..dimensions {
+        let x = a(k) ^ b(k);
+        if msb < x && msb < (msb ^ x) {
+            dimension = k;
+            msb = x;
+        }
+    }
+
+    a(dimension).cmp(&b(dimension))
+}
+
+pub fn coordinates(position: &Position) -> Vec<u64> {
+    (0..position.dimensions())
+        .map(|k| position[k].u64())
+        .collect()
+}
+
+/// Read-only index over a section of a memory-mapped file.
+///
+/// A section is a list of little-endian `u64` words, laid out as:
+///
+///  * `dimensions`, `count`, `values`: the number of coordinates per
+///    position, the number of records and the number of distinct
+///    values.
+///
+///  * `count` records of `dimensions` coordinates followed by the
+///    value, sorted along the Z-order curve of the positions.
+///
+///  * `values + 1` offsets within the postings, the postings of the
+///    value `v` being stored from `offsets[v]` to `offsets[v + 1]`.
+///
+///  * `count` postings, which are the record numbers, grouped by value.
+#[derive(Clone)]
+pub struct MappedIndex {
+    data: Arc<Mmap>,
+    dimensions: usize,
+    count: usize,
+    values: usize,
+    records: usize,
+    offsets: usize,
+    postings: usize,
+}
+
+impl MappedIndex {
+    pub fn new(data: Arc<Mmap>, offset: usize) -> Result<Self, Error> {
+        let truncated = || Error::Storage(format!("Truncated index section at offset {}", offset));
+
+        if offset + 3 * WORD > data.len() {
+            return Err(truncated());
+        }
+
+        let word =
+            |i: usize| u64::from_le_bytes(*array_ref!(data, offset + i * WORD, WORD)) as usize;
+        let dimensions = word(0);
+        let count = word(1);
+        let values = word(2);
+
+        // The sizes are read from the file, so make sure they do not
+        // overflow before checking they fit.
+        let layout = || {
+            let records = offset + 3 * WORD;
+            let offsets = records.checked_add(
+                count
+                    .checked_mul(dimensions.checked_add(1)?)?
+                    .checked_mul(WORD)?,
+            )?;
+            let postings = offsets.checked_add(values.checked_add(1)?.checked_mul(WORD)?)?;
+            let end = postings.checked_add(count.checked_mul(WORD)?)?;
+
+            Some((records, offsets, postings, end))
+        };
+
+        let (records, offsets, postings) = match layout() {
+            Some((records, offsets, postings, end)) if end <= data.len() => {
+                (records, offsets, postings)
+            }
+            _ => return Err(truncated()),
+        };
+
+        Ok(MappedIndex {
+            data,
+            dimensions,
+            count,
+            values,
+            records,
+            offsets,
+            postings,
+        })
+    }
+
+    // Write a section, and return the number of bytes written.
+    //
+    // `values` is the number of distinct values, all the values of the
+    // records have to be smaller.
+    pub fn write<W>(
+        writer: &mut W,
+        dimensions: usize,
+        values: usize,
+        mut records: Vec<(Position, usize)>,
+    ) -> Result<usize, Error>
+    where
+        W: Write,
+    {
+        let mut words = Vec::with_capacity(3 + records.len() * (dimensions + 2) + values + 1);
+
+        words.push(dimensions as u64);
+        words.push(records.len() as u64);
+        words.push(values as u64);
+
+        let mut records = records
+            .drain(..)
+            .map(|(position, value)| {
+                if position.dimensions() != dimensions {
+                    Err(Error::DimensionMismatch(dimensions, position.dimensions()))
+                } else if value >= values {
+                    Err(Error::OutOfBounds(format!("value {} >= {}", value, values)))
+                } else {
+                    Ok((coordinates(&position), value))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        records.sort_unstable_by(|(a, _), (b, _)| morton_cmp(dimensions, |k| a[k], |k| b[k]));
+
+        // Count the records per value, and compute the offsets of the
+        // postings of each value.
+        let mut offsets = vec![0usize; values + 1];
+        for (coordinates, value) in &records {
+            words.extend(coordinates);
+            words.push(*value as u64);
+            offsets[*value + 1] += 1;
+        }
+        for v in 0..values {
+            offsets[v + 1] += offsets[v];
+        }
+        words.extend(offsets.iter().map(|&offset| offset as u64));
+
+        let mut postings = vec![0u64; records.len()];
+        for (i, (_, value)) in records.iter().enumerate() {
+            postings[offsets[*value]] = i as u64;
+            offsets[*value] += 1;
+        }
+        words.extend(postings);
+
+        for word in &words {
+            writer.write_all(&word.to_le_bytes())?;
+        }
+
+        Ok(words.len() * WORD)
+    }
+
+    // Write a section from records already sorted along the Z-order curve,
+    // at the current position of `file`, and return the number of bytes
+    // written.
+    //
+    // Unlike `write`, the records are not kept in memory, the postings are
+    // filled in place through a mapping of the section, so `file` has to
+    // be opened for reading and writing.
+    pub fn write_sorted<I>(
+        file: &mut File,
+        dimensions: usize,
+        values: usize,
+        records: I,
+    ) -> Result<usize, Error>
+    where
+        I: Iterator<Item = Result<(Vec<u64>, usize), Error>>,
+    {
+        let start = file.stream_position()?;
+        let mut offsets = vec![0usize; values + 1];
+        let mut count = 0;
+
+        {
+            let mut writer = BufWriter::new(&mut *file);
+
+            // The number of records is updated once known.
+            for word in &[dimensions, 0, values] {
+                writer.write_all(&(*word as u64).to_le_bytes())?;
+            }
+
+            for record in records {
+                let (coordinates, value) = record?;
+                if coordinates.len() != dimensions {
+                    return Err(Error::DimensionMismatch(dimensions, coordinates.len()));
+                } else if value >= values {
+                    return Err(Error::OutOfBounds(format!("value {} >= {}", value, values)));
+                }
+
+                for coordinate in coordinates {
+                    writer.write_all(&coordinate.to_le_bytes())?;
+                }
+                writer.write_all(&(value as u64).to_le_bytes())?;
+
+                offsets[value + 1] += 1;
+                count += 1;
+            }
+
+            for v in 0..values {
+                offsets[v + 1] += offsets[v];
+            }
+            for offset in &offsets {
+                writer.write_all(&(*offset as u64).to_le_bytes())?;
+            }
+
+            writer.flush()?;
+        }
+
+        let length = (3 + count * (dimensions + 2) + values + 1) * WORD;
+        file.set_len(start + length as u64)?;
+
+        {
+            let mut section =
+                unsafe { MmapOptions::new().offset(start).len(length).map_mut(file)? };
+
+            section[WORD..2 * WORD].copy_from_slice(&(count as u64).to_le_bytes());
+
+            let records = 3 * WORD;
+            let postings = records + (count * (dimensions + 1) + values + 1) * WORD;
+            for record in 0..count {
+                let at = records + (record * (dimensions + 1) + dimensions) * WORD;
+                let value = u64::from_le_bytes(*array_ref!(section, at, WORD)) as usize;
+
+                let at = postings + offsets[value] * WORD;
+                section[at..at + WORD].copy_from_slice(&(record as u64).to_le_bytes());
+                offsets[value] += 1;
+            }
+
+            section.flush()?;
+        }
+
+        file.seek(SeekFrom::Start(start + length as u64))?;
+
+        Ok(length)
+    }
+
+    fn word(&self, offset: usize) -> u64 {
+        u64::from_le_bytes(*array_ref!(self.data, offset, WORD))
+    }
+
+    fn coordinate(&self, record: usize, k: usize) -> u64 {
+        self.word(self.records + (record * (self.dimensions + 1) + k) * WORD)
+    }
+
+    fn value(&self, record: usize) -> usize {
+        self.coordinate(record, self.dimensions) as usize
+    }
+
+    fn position(&self, record: usize) -> Position {
+        (0..self.dimensions)
+            .map(|k| self.coordinate(record, k))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    // Number of records strictly before `key` along the curve, or up to and
+    // including `key` when `inclusive` is set.
+    fn bound(&self, key: &[u64], inclusive: bool) -> usize {
+        let (mut low, mut high) = (0, self.count);
+
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let ordering = morton_cmp(self.dimensions, |k| self.coordinate(middle, k), |k| key[k]);
+
+            if ordering == Ordering::Less || (inclusive && ordering == Ordering::Equal) {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+
+        low
+    }
+
+    // Inputs and Results are expressed in encoded space coordinates.
+    pub fn find(&self, key: &Position) -> Vec<usize> {
+        if key.dimensions() != self.dimensions {
+            return vec![];
+        }
+
+        let key = coordinates(key);
+
+        (self.bound(&key, false)..self.bound(&key, true))
+            .map(|record| self.value(record))
+            .collect()
+    }
+
+    // Inputs and Results are expressed in encoded space coordinates.
+    pub fn find_range(&self, start: &Position, end: &Position) -> Vec<(Position, usize)> {
+        if start.dimensions() != self.dimensions || end.dimensions() != self.dimensions {
+            return vec![];
+        }
+
+        let start = coordinates(start);
+        let end = coordinates(end);
+
+        // All the positions within the box are located on the curve between
+        // its two corners, but not all the positions between the corners are
+        // within the box.
+        (self.bound(&start, false)..self.bound(&end, true))
+            .filter(|&record| {
+                (0..self.dimensions).all(|k| {
+                    let c = self.coordinate(record, k);
+                    start[k] <= c && c <= end[k]
+                })
+            })
+            .map(|record| (self.position(record), self.value(record)))
+            .collect()
+    }
+
+    // Results are expressed in encoded space coordinates.
+    pub fn find_by_value(&self, value: usize) -> Vec<Position> {
+        if value >= self.values {
+            return vec![];
+        }
+
+        let first = self.word(self.offsets + value * WORD) as usize;
+        let last = self.word(self.offsets + (value + 1) * WORD) as usize;
+
+        (first..last)
+            .map(|posting| self.position(self.word(self.postings + posting * WORD) as usize))
+            .collect()
+    }
+}
+
+/// Read-only table of values, stored using bincode, over a section of a
+/// memory-mapped file.
+///
+/// A section is laid out as:
+///
+///  * `count`, as a little-endian `u64` word: the number of values.
+///
+///  * `count + 1` little-endian `u64` offsets within the values, the
+///    value `i` being stored from `offsets[i]` to `offsets[i + 1]`.
+///
+///  * The values, serialized one after the other.
+#[derive(Clone)]
+pub struct MappedTable {
+    data: Arc<Mmap>,
+    count: usize,
+    offsets: usize,
+    values: usize,
+}
+
+impl MappedTable {
+    pub fn new(data: Arc<Mmap>, offset: usize) -> Result<Self, Error> {
+        let truncated = || Error::Storage(format!("Truncated table section at offset {}", offset));
+
+        if offset + WORD > data.len() {
+            return Err(truncated());
+        }
+
+        let count = u64::from_le_bytes(*array_ref!(data, offset, WORD)) as usize;
+
+        let offsets = offset + WORD;
+        let values = match count
+            .checked_add(1)
+            .and_then(|n| n.checked_mul(WORD))
+            .and_then(|n| n.checked_add(offsets))
+        {
+            Some(values) if values <= data.len() => values,
+            _ => return Err(truncated()),
+        };
+
+        let table = MappedTable {
+            data,
+            count,
+            offsets,
+            values,
+        };
+
+        if table.values + table.offset(count) > table.data.len() {
+            return Err(truncated());
+        }
+
+        Ok(table)
+    }
+
+    // Write a section, and return the number of bytes written.
+    pub fn write<W, T, I>(writer: &mut W, values: I) -> Result<usize, Error>
+    where
+        W: Write,
+        T: Serialize,
+        I: Iterator<Item = T>,
+    {
+        let mut offsets = vec![0u64];
+        let mut bytes = vec![];
+
+        for value in values {
+            if let Err(e) = bincode::serialize_into(&mut bytes, &value) {
+                return Err(Error::Storage(format!(
+                    "Bincode could not serialize: {:?}",
+                    e
+                )));
+            }
+            offsets.push(bytes.len() as u64);
+        }
+
+        writer.write_all(&((offsets.len() - 1) as u64).to_le_bytes())?;
+        for offset in &offsets {
+            writer.write_all(&offset.to_le_bytes())?;
+        }
+        writer.write_all(&bytes)?;
+
+        Ok((offsets.len() + 1) * WORD + bytes.len())
+    }
+
+    fn offset(&self, i: usize) -> usize {
+        u64::from_le_bytes(*array_ref!(self.data, self.offsets + i * WORD, WORD)) as usize
+    }
+
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn get<T>(&self, i: usize) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let (start, end) = (self.offset(i), self.offset(i + 1));
+        if start > end || self.values + end > self.data.len() {
+            return Err(Error::Storage(format!("Invalid table entry {}", i)));
+        }
+
+        // Bound the allocations by the size of the entry, in case it is
+        // corrupted.
+        bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .with_limit((end - start) as u64)
+            .deserialize(&self.data[self.values + start..self.values + end])
+            .map_err(|e| Error::Storage(format!("Bincode could not deserialize: {:?}", e)))
+    }
+}
+
+impl Debug for MappedTable {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "MappedTable {{ count: {} }}", self.count)
+    }
+}
+
+impl Debug for MappedIndex {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "MappedIndex {{ dimensions: {}, count: {}, values: {} }}",
+            self.dimensions, self.count, self.values
+        )
+    }
+}
@@ -1,34 +1,112 @@
+mod attributes;
 mod db_core;
+mod error;
+pub(crate) mod mapped_index;
 pub mod space;
-mod space_db;
+pub(crate) mod space_db;
 pub(crate) mod space_index;
+mod table;
 
-use std::collections::HashMap;
+#[cfg(test)]
+mod tests;
 
-use ironsea_index::Indexed;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+use std::sync::RwLockReadGuard;
+use std::sync::RwLockWriteGuard;
 
 use super::storage;
+pub use attributes::Attributes;
+pub use attributes::Predicate;
+pub use attributes::Value;
 pub use db_core::Core;
 pub use db_core::CoreQueryParameters;
 pub use db_core::Properties;
+pub use db_core::Summary;
+pub use error::Error;
 use space::Position;
 use space::Space;
 
 /// Selected tuples matching a query.
 ///
 /// This is either:
-///  * `Err` with a reason stored as a `String`
+///  * `Err` with the reason of the failure, see [Error](enum.Error.html)
 ///  * `Ok`, with a vector of tuples defined as:
 ///        `(Space Name, [(Position, Properties)])`
-pub type ResultSet<'r> = Result<Vec<(&'r String, Vec<(Position, &'r Properties)>)>, String>;
+///
+/// The attributes of the *identifiers* are not repeated for each
+/// position, they are retrieved once per *identifier* with
+/// [Core::attributes](struct.Core.html#method.attributes), or with
+/// [Properties::load_attributes] once the results are converted.
+///
+/// [Properties::load_attributes]: storage/model/struct.Properties.html#method.load_attributes
+pub type ResultSet<'r> = Result<Vec<(&'r String, Vec<(Position, &'r Properties)>)>, Error>;
 
-type ReferenceSpaceIndex = ironsea_index_hashmap::Index<Space, String>;
-type CoreIndex = ironsea_index_hashmap::Index<Core, String>;
+/// Counts of positions binned on a grid.
+///
+/// This is either:
+///  * `Err` with the reason of the failure, see [Error](enum.Error.html)
+///  * `Ok`, with a vector of tuples defined as:
+///        `(Space Name, [(Position, Count)])`
+pub type Histogram<'r> = Result<Vec<(&'r String, Vec<(Position, usize)>)>, Error>;
 
 /// Collection of datasets and their reference spaces.
+///
+/// Datasets can be registered, replaced or removed at runtime, while
+/// other threads keep querying. The datasets and reference spaces are
+/// shared, and only the registry itself is locked, for the duration of
+/// a lookup or of an update. The new datasets should be loaded
+/// beforehand, with [load_core](#method.load_core), and queries already
+/// running keep using the datasets they started with.
 pub struct DataBase {
-    reference_spaces: ReferenceSpaceIndex,
-    cores: CoreIndex,
+    universe: Arc<Space>,
+    reference_spaces: RwLock<Registry<Space>>,
+    cores: RwLock<Registry<Core>>,
+}
+
+// Shared values, by name, and the ordered list of the names.
+struct Registry<T> {
+    values: HashMap<String, Arc<T>>,
+    keys: Vec<String>,
+}
+
+impl<T> Registry<T> {
+    fn new() -> Self {
+        Registry {
+            values: HashMap::new(),
+            keys: vec![],
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<Arc<T>> {
+        self.values.get(name).cloned()
+    }
+
+    fn insert(&mut self, name: &str, value: T) -> Option<Arc<T>> {
+        if let Err(i) = self.keys.binary_search_by(|key| key.as_str().cmp(name)) {
+            self.keys.insert(i, name.to_string());
+        }
+
+        self.values.insert(name.to_string(), Arc::new(value))
+    }
+
+    fn remove(&mut self, name: &str) -> Option<Arc<T>> {
+        self.keys.retain(|key| key != name);
+        self.values.remove(name)
+    }
+}
+
+// The registries are only updated by single insertions and removals,
+// which leave them consistent even if a thread panicked while holding
+// the lock.
+fn read<T>(lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    lock.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn write<T>(lock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    lock.write().unwrap_or_else(PoisonError::into_inner)
 }
 
 impl DataBase {
@@ -37,85 +115,216 @@ impl DataBase {
     /// # Parameters
     ///
     ///  * `spaces`:
-    ///      List of reference spaces.
+    ///      List of reference spaces. A name must not be used for two
+    ///      different definitions.
     ///
     ///  * `cores`:
     ///      List of datasets (cores) which will be queried through this
-    ///      `DataBase` struct.
+    ///      `DataBase` struct. Their names must be unique.
     // TODO: Replace vectors with iterators?
-    pub fn new(spaces: Vec<Space>, cores: Vec<Core>) -> Self {
-        DataBase {
-            reference_spaces: ReferenceSpaceIndex::new(spaces.into_iter()),
-            cores: CoreIndex::new(cores.into_iter()),
+    pub fn new(spaces: Vec<Space>, cores: Vec<Core>) -> Result<Self, Error> {
+        let mut merged = HashMap::new();
+        DataBase::merge_spaces(&mut merged, spaces)?;
+
+        let mut reference_spaces = Registry::new();
+        for (name, space) in merged {
+            reference_spaces.insert(&name, space);
+        }
+
+        let mut registry = Registry::new();
+        for core in cores {
+            let name = core.name().clone();
+            if registry.insert(&name, core).is_some() {
+                return Err(Error::Conflict(format!(
+                    "Core `{}` is defined two times",
+                    name
+                )));
+            }
         }
+
+        Ok(DataBase {
+            universe: Arc::new(Space::universe().clone()),
+            reference_spaces: RwLock::new(reference_spaces),
+            cores: RwLock::new(registry),
+        })
     }
 
     /// Load a list of indices.
     ///
+    /// The header of each index file is validated, and an error is
+    /// returned if the format version is not supported, or if the file
+    /// is truncated or corrupted.
+    ///
     /// # Parameters
     ///
     ///  * `indices`:
     ///      The list of index file names to load.
-    pub fn load(indices: &[&str]) -> Result<Self, String> {
+    pub fn load(indices: &[&str]) -> Result<Self, Error> {
         let mut spaces = HashMap::new();
         let mut cores = vec![];
 
         for index in indices.iter() {
             let (core_spaces, core) = DataBase::load_core(index)?;
-            for core_space in core_spaces {
-                if let Some(space) = spaces.get(core_space.name()) {
-                    // Space is already registered, but with a different definitions.
-                    if space != &core_space {
-                        return Err(format!(
-                            "Reference Space ID `{}` defined two times, but differently\n{:?}\n VS \n{:?}",
-                            core_space.name(),
-                            spaces.get(core_space.name()),
-                            core_space
-                        ));
-                    }
-                } else {
-                    spaces.insert(core_space.name().clone(), core_space);
-                }
-            }
+            DataBase::merge_spaces(&mut spaces, core_spaces)?;
 
             cores.push(core);
         }
 
         let spaces = spaces.drain().map(|(_, v)| v).collect();
 
-        Ok(DataBase::new(spaces, cores))
+        DataBase::new(spaces, cores)
     }
 
-    fn load_core(name: &str) -> Result<(Vec<Space>, Core), String> {
-        match storage::bincode::load(name) {
-            Err(e) => Err(format!("Index deserialization error: {:?}", e)),
+    /// Load a single index, to be registered with
+    /// [add_core](#method.add_core) or
+    /// [replace_core](#method.replace_core).
+    ///
+    /// # Parameters
+    ///
+    ///  * `name`:
+    ///      The index file name to load.
+    ///
+    /// Both bincode and memory-mapped indices are supported, the format
+    /// is detected from the content of the file.
+    pub fn load_core(name: &str) -> Result<(Vec<Space>, Core), Error> {
+        let index = match storage::mapped::is_mapped(name) {
+            Ok(true) => storage::mapped::load(name),
+            Ok(false) => storage::bincode::load_index(name),
+            Err(e) => Err(e),
+        };
+
+        match index {
+            Err(e) => Err(Error::Storage(format!(
+                "Cannot load index `{}`: {}",
+                name, e
+            ))),
             Ok(index) => Ok(index),
         }
     }
 
-    fn check_exactly_one<'t, T>(list: &[&'t T], name: &str, value: &str) -> Result<&'t T, String> {
-        if list.len() > 1 {
-            Err(format!(
-                "Multiple {} registered under `{}`: {}",
-                name,
-                value,
-                list.len()
-            ))
-        } else if list.is_empty() {
-            Err(format!(
-                "No {} registered under `{}`: {}",
-                name,
-                value,
-                list.len()
-            ))
-        } else {
-            Ok(&list[0])
+    // Add `core_spaces` to `spaces`, making sure a reference space name is
+    // not used for two different definitions.
+    fn merge_spaces(
+        spaces: &mut HashMap<String, Space>,
+        core_spaces: Vec<Space>,
+    ) -> Result<(), Error> {
+        for core_space in core_spaces {
+            if let Some(space) = spaces.get(core_space.name()) {
+                // Space is already registered, but with a different definitions.
+                if space != &core_space {
+                    return Err(Error::Conflict(format!(
+                        "Reference Space ID `{}` defined two times, but differently\n{:?}\n VS \n{:?}",
+                        core_space.name(),
+                        spaces.get(core_space.name()),
+                        core_space
+                    )));
+                }
+            } else {
+                spaces.insert(core_space.name().clone(), core_space);
+            }
+        }
+
+        Ok(())
+    }
+
+    // Register the reference spaces not yet known, after checking they do
+    // not conflict with the ones already registered.
+    fn register_spaces(
+        reference_spaces: &mut Registry<Space>,
+        core_spaces: Vec<Space>,
+    ) -> Result<(), Error> {
+        let mut spaces = core_spaces
+            .iter()
+            .filter_map(|space| reference_spaces.get(space.name()))
+            .map(|space| (space.name().clone(), space.as_ref().clone()))
+            .collect::<HashMap<_, _>>();
+
+        DataBase::merge_spaces(&mut spaces, core_spaces)?;
+
+        for (name, space) in spaces {
+            if reference_spaces.get(&name).is_none() {
+                reference_spaces.insert(&name, space);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Register a new dataset.
+    ///
+    /// # Parameters
+    ///
+    ///  * `spaces`:
+    ///      The reference spaces used by the dataset. They must match
+    ///      the definitions of the reference spaces already registered
+    ///      under the same names.
+    ///
+    ///  * `core`:
+    ///      The dataset to add. Its name must not be already in use.
+    pub fn add_core(&self, spaces: Vec<Space>, core: Core) -> Result<(), Error> {
+        let mut reference_spaces = write(&self.reference_spaces);
+        let mut cores = write(&self.cores);
+
+        if cores.get(core.name()).is_some() {
+            return Err(Error::Conflict(format!(
+                "Core `{}` is already registered",
+                core.name()
+            )));
+        }
+
+        DataBase::register_spaces(&mut reference_spaces, spaces)?;
+        cores.insert(&core.name().clone(), core);
+
+        Ok(())
+    }
+
+    /// Unregister a dataset, which is returned.
+    ///
+    /// The reference spaces are kept registered.
+    ///
+    /// # Parameters
+    ///
+    ///  * `name`:
+    ///      The name of the dataset (core) to remove.
+    pub fn remove_core(&self, name: &str) -> Result<Arc<Core>, Error> {
+        match write(&self.cores).remove(name) {
+            None => Err(Error::UnknownCore(name.to_string())),
+            Some(core) => Ok(core),
+        }
+    }
+
+    /// Replace a registered dataset by a new one with the same name,
+    /// for example a new revision. The previous dataset is returned.
+    ///
+    /// # Parameters
+    ///
+    ///  * `spaces`:
+    ///      The reference spaces used by the dataset. They must match
+    ///      the definitions of the reference spaces already registered
+    ///      under the same names.
+    ///
+    ///  * `core`:
+    ///      The dataset to use instead of the one registered under the
+    ///      same name.
+    pub fn replace_core(&self, spaces: Vec<Space>, core: Core) -> Result<Arc<Core>, Error> {
+        let mut reference_spaces = write(&self.reference_spaces);
+        let mut cores = write(&self.cores);
+
+        if cores.get(core.name()).is_none() {
+            return Err(Error::UnknownCore(core.name().clone()));
+        }
+
+        DataBase::register_spaces(&mut reference_spaces, spaces)?;
+
+        match cores.insert(&core.name().clone(), core) {
+            None => unreachable!(),
+            Some(previous) => Ok(previous),
         }
     }
 
     /// Returns an ordered list of the reference space names registered.
-    pub fn space_keys(&self) -> &Vec<String> {
-        self.reference_spaces.keys()
+    pub fn space_keys(&self) -> Vec<String> {
+        read(&self.reference_spaces).keys.clone()
     }
 
     /// Lookup a space within the reference spaces registered.
@@ -124,42 +333,238 @@ impl DataBase {
     ///
     ///  * `name`:
     ///      The name of the reference space to search for.
-    pub fn space(&self, name: &str) -> Result<&Space, String> {
-        if name == space::Space::universe().name() {
-            Ok(space::Space::universe())
+    pub fn space(&self, name: &str) -> Result<Arc<Space>, Error> {
+        if name == self.universe.name() {
+            Ok(self.universe.clone())
         } else {
-            let r = self.reference_spaces.find(&name.to_string());
-
-            Self::check_exactly_one(&r, "spaces", name)
+            match read(&self.reference_spaces).get(name) {
+                None => Err(Error::UnknownSpace(name.to_string())),
+                Some(space) => Ok(space),
+            }
         }
     }
 
     /// Returns an ordered list of dataset (Core) names registered.
-    pub fn core_keys(&self) -> &Vec<String> {
-        self.cores.keys()
+    pub fn core_keys(&self) -> Vec<String> {
+        read(&self.cores).keys.clone()
     }
 
     /// Lookup a dataset within the datasets registered.
     ///
+    /// The dataset stays available through the returned value, even if
+    /// it is removed or replaced in the meantime.
+    ///
     /// # Parameters
     ///
     ///  * `name`:
     ///      The name of the dataset (core) to search for.
-    pub fn core(&self, name: &str) -> Result<&Core, String> {
-        let r = self.cores.find(&name.to_string());
-
-        Self::check_exactly_one(&r, "cores", name)
+    pub fn core(&self, name: &str) -> Result<Arc<Core>, Error> {
+        match read(&self.cores).get(name) {
+            None => Err(Error::UnknownCore(name.to_string())),
+            Some(core) => Ok(core),
+        }
     }
-}
 
-impl ironsea_index::Record<String> for Space {
-    fn key(&self) -> String {
-        self.name().clone()
+    /// Search the pairs of *identifiers* of two datasets located within
+    /// a distance of each other.
+    ///
+    /// The positions of both datasets are rebased into a common space,
+    /// which is the `output_space` of the parameters if set, the
+    /// Universe otherwise. Distances are measured in the decoded
+    /// coordinates of that space, and positions outside of it are
+    /// ignored.
+    ///
+    /// The positions of `right` are searched through its index, around
+    /// each position of `left`, so `left` should be the smaller dataset.
+    ///
+    /// Each pair is returned once, with the smallest distance between
+    /// the positions of its identifiers, and the pairs are sorted by
+    /// increasing distance.
+    ///
+    /// # Parameters
+    ///
+    ///  * `parameters`:
+    ///      Search parameters, see [CoreQueryParameters](struct.CoreQueryParameters.html).
+    ///
+    ///  * `left`:
+    ///      The name of the dataset providing the first element of the
+    ///      pairs.
+    ///
+    ///  * `right`:
+    ///      The name of the dataset providing the second element of the
+    ///      pairs.
+    ///
+    ///  * `distance`:
+    ///      The maximum distance, included, between the two elements of
+    ///      a pair.
+    pub fn join_by_distance(
+        &self,
+        parameters: &CoreQueryParameters,
+        left: &str,
+        right: &str,
+        distance: f64,
+    ) -> Result<Vec<(Properties, Properties, f64)>, Error> {
+        if distance.is_nan() || distance < 0.0 {
+            return Err(Error::InvalidDefinition(format!(
+                "Invalid join distance {}",
+                distance
+            )));
+        }
+
+        let common = match parameters.output_space {
+            None => Space::universe().name().as_str(),
+            Some(output_space) => output_space,
+        };
+
+        let common = self.space(common)?;
+
+        // Length in the Universe of the join distance. A unit of each
+        // axis of the common space spans at most this length, so that
+        // any pair of positions within `distance` of each other is
+        // within `radius` in the Universe.
+        let radius = if common.as_ref() == Space::universe() {
+            distance
+        } else {
+            distance
+                * common
+                    .axes()
+                    .iter()
+                    .map(|a| a.project_out_vector(1.0).norm() / a.graduation().epsilon)
+                    .sum::<f64>()
+        };
+
+        // Look up the right positions around each left position through
+        // the index, instead of retrieving the whole right dataset.
+        let (left, right) = (self.core(left)?, self.core(right)?);
+
+        // Smallest distance found for each pair of identifiers.
+        let mut pairs = HashMap::new();
+        for (a, position_a, absolute) in left.decoded_positions(parameters, &common)? {
+            for (b, position_b) in
+                right.decoded_positions_around(parameters, &absolute, radius, &common)?
+            {
+                let d = position_a
+                    .iter()
+                    .zip(position_b.iter())
+                    .map(|(a, b)| (a - b) * (a - b))
+                    .sum::<f64>()
+                    .sqrt();
+
+                if d <= distance {
+                    let closest = pairs.entry((a, b)).or_insert(d);
+                    if d < *closest {
+                        *closest = d;
+                    }
+                }
+            }
+        }
+
+        let mut pairs = pairs.into_iter().collect::<Vec<_>>();
+        pairs.sort_unstable_by(|((a1, a2), da), ((b1, b2), db)| {
+            da.total_cmp(db)
+                .then(a1.key().cmp(&b1.key()))
+                .then(a2.key().cmp(&b2.key()))
+        });
+
+        Ok(pairs
+            .into_iter()
+            .map(|((a, b), d)| (a.clone(), b.clone(), d))
+            .collect())
     }
-}
 
-impl ironsea_index::Record<String> for Core {
-    fn key(&self) -> String {
-        self.name().clone()
+    /// Search the pairs of *identifiers* of two datasets which overlap,
+    /// with the number of cells they share.
+    ///
+    /// Both datasets are rasterised at the resolution selected by the
+    /// parameters, and their cells are mapped, through their centers,
+    /// into the cells of a common reference space. The cells of that
+    /// space are compared at the smallest power of two of its steps
+    /// which is at least as large as the coarsest of the selected
+    /// resolutions.
+    ///
+    /// The pairs are sorted by decreasing number of shared cells.
+    ///
+    /// # Parameters
+    ///
+    ///  * `parameters`:
+    ///      Search parameters, see [CoreQueryParameters](struct.CoreQueryParameters.html).
+    ///
+    ///  * `left`:
+    ///      The name of the dataset providing the first element of the
+    ///      pairs.
+    ///
+    ///  * `right`:
+    ///      The name of the dataset providing the second element of the
+    ///      pairs.
+    ///
+    ///  * `space_id`:
+    ///      The name of the reference space whose cells are compared.
+    pub fn join_by_overlap(
+        &self,
+        parameters: &CoreQueryParameters,
+        left: &str,
+        right: &str,
+        space_id: &str,
+    ) -> Result<Vec<(Properties, Properties, usize)>, Error> {
+        let space = self.space(space_id)?;
+        if space.as_ref() == Space::universe() {
+            return Err(Error::InvalidDefinition(format!(
+                "The {} has no cells to compare",
+                space_id
+            )));
+        }
+
+        let left = self.core(left)?;
+        let right = self.core(right)?;
+
+        // Length in the Universe of the coarsest cells selected on either
+        // side, along any of their axes.
+        let mut coarsest = 0.0f64;
+        for s in left.space_db().iter().chain(right.space_db().iter()) {
+            let scale = s.resolutions()[s.resolution(parameters)].scale()[0];
+            for a in self.space(s.name())?.axes() {
+                coarsest = coarsest.max(a.project_out_vector((1u64 << scale) as f64).norm());
+            }
+        }
+
+        // Bits of precision to remove from the coordinates of `space` for
+        // its cells to be at least that large, as the steps of `space`
+        // may differ from the steps of the datasets spaces.
+        let shift = space
+            .axes()
+            .iter()
+            .map(|a| {
+                let ratio = coarsest / a.project_out_vector(1.0).norm();
+                // Tolerate rounding errors on exact powers of two.
+                (ratio.log2() - 1e-9).ceil().max(0.0) as u32
+            })
+            .max()
+            .unwrap_or(0);
+
+        let left = left.cells(parameters, &space, shift)?;
+        let right = right.cells(parameters, &space, shift)?;
+
+        let mut pairs = HashMap::new();
+        for (cell, a) in &left {
+            if let Some(b) = right.get(cell) {
+                for &a in a {
+                    for &b in b {
+                        *pairs.entry((a, b)).or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+
+        let mut pairs = pairs.into_iter().collect::<Vec<_>>();
+        pairs.sort_unstable_by(|((a1, a2), ca), ((b1, b2), cb)| {
+            cb.cmp(ca)
+                .then(a1.key().cmp(&b1.key()))
+                .then(a2.key().cmp(&b2.key()))
+        });
+
+        Ok(pairs
+            .into_iter()
+            .map(|((a, b), c)| (a.clone(), b.clone(), c))
+            .collect())
     }
 }
@@ -1,8 +1,11 @@
+use std::convert::TryFrom;
+
 use serde::Deserialize;
 use serde::Serialize;
 
 use super::coordinate::Coordinate;
 use super::position::Position;
+use crate::Error;
 
 /// Mathematical set numbers.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -17,14 +20,19 @@ pub enum NumberSet {
     R,
 }
 
-impl From<&str> for NumberSet {
-    fn from(set: &str) -> Self {
+impl TryFrom<&str> for NumberSet {
+    type Error = Error;
+
+    fn try_from(set: &str) -> Result<Self, Self::Error> {
         match set {
-            "N" => NumberSet::N,
-            "Z" => NumberSet::Z,
-            "Q" => NumberSet::Q,
-            "R" => NumberSet::R,
-            _ => panic!("Invalid set number: '{}', expected: N, Z, Q, R", set),
+            "N" => Ok(NumberSet::N),
+            "Z" => Ok(NumberSet::Z),
+            "Q" => Ok(NumberSet::Q),
+            "R" => Ok(NumberSet::R),
+            _ => Err(Error::InvalidDefinition(format!(
+                "Invalid set number: '{}', expected: N, Z, Q, R",
+                set
+            ))),
         }
     }
 }
@@ -59,7 +67,13 @@ pub struct Graduation {
 }
 
 impl Graduation {
-    fn new(set: NumberSet, minimum: f64, maximum: f64, steps: u64) -> Result<Self, String> {
+    fn new(set: NumberSet, minimum: f64, maximum: f64, steps: u64) -> Result<Self, Error> {
+        if steps == 0 {
+            return Err(Error::InvalidDefinition(
+                "Graduation: the number of steps must be greater than 0".to_string(),
+            ));
+        }
+
         Ok(Graduation {
             set,
             minimum,
@@ -110,17 +124,19 @@ impl UnitSI {
     }
 }
 
-impl From<&str> for UnitSI {
-    fn from(name: &str) -> Self {
+impl TryFrom<&str> for UnitSI {
+    type Error = Error;
+
+    fn try_from(name: &str) -> Result<Self, Self::Error> {
         match name {
-            "m" => UnitSI::m,
-            "dm" => UnitSI::dm,
-            "cm" => UnitSI::cm,
-            "mm" => UnitSI::mm,
-            "um" => UnitSI::um,
-            "nm" => UnitSI::nm,
-            "pm" => UnitSI::pm,
-            _ => unimplemented!("Unknown unit '{}'", name),
+            "m" => Ok(UnitSI::m),
+            "dm" => Ok(UnitSI::dm),
+            "cm" => Ok(UnitSI::cm),
+            "mm" => Ok(UnitSI::mm),
+            "um" => Ok(UnitSI::um),
+            "nm" => Ok(UnitSI::nm),
+            "pm" => Ok(UnitSI::pm),
+            _ => Err(Error::InvalidDefinition(format!("Unknown unit '{}'", name))),
         }
     }
 }
@@ -171,13 +187,13 @@ impl Axis {
         minimum: f64,
         maximum: f64,
         steps: u64,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, Error> {
         // Convert to Position, and ensure it is a unit vector.
         let unit_vector = Position::from(unit_vector).unit();
         let graduation = Graduation::new(set, minimum, maximum, steps)?;
 
         Ok(Axis {
-            measurement_unit: unit.into(),
+            measurement_unit: UnitSI::try_from(unit)?,
             graduation,
             unit_vector,
         })
@@ -225,7 +241,7 @@ impl Axis {
     ///      Universe coordinates, but with any translations already
     ///      applied so that the origin of the vector is the origin of
     ///      this axis.
-    pub fn project_in(&self, position: &Position) -> Result<Coordinate, String> {
+    pub fn project_in(&self, position: &Position) -> Result<Coordinate, Error> {
         let max = self.graduation.maximum;
         let min = self.graduation.minimum;
 
@@ -274,7 +290,7 @@ impl Axis {
     ///  * `coordinate`:
     ///      The coordinate to project out of this axis. It must be
     ///      defined as an encoded coordinate on this axis.
-    pub fn project_out(&self, coordinate: &Coordinate) -> Result<Position, String> {
+    pub fn project_out(&self, coordinate: &Coordinate) -> Result<Position, Error> {
         let d = self.decode(coordinate)?;
 
         // Apply Unit scaling
@@ -283,6 +299,35 @@ impl Axis {
         Ok(&self.unit_vector * d)
     }
 
+    /// Project a vector on this axis.
+    ///
+    /// Unlike positions, vectors are neither shifted, clipped, nor
+    /// rounded to the graduation. The resulting length is expressed in
+    /// encoded steps on this axis, and may be fractional.
+    ///
+    /// # Parameters
+    ///
+    ///  * `vector`:
+    ///      The vector to project on this axis. It must be defined in
+    ///      the Universe space.
+    pub fn project_in_vector(&self, vector: &Position) -> f64 {
+        vector.dot_product(&self.unit_vector)
+            / self.measurement_unit.factor()
+            / self.graduation.epsilon
+    }
+
+    /// Convert a length expressed in encoded steps on this axis into a
+    /// vector in the Universe space.
+    ///
+    /// # Parameters
+    ///
+    ///  * `length`:
+    ///      The length to project out of this axis, which may be
+    ///      fractional.
+    pub fn project_out_vector(&self, length: f64) -> Position {
+        &self.unit_vector * (length * self.graduation.epsilon * self.measurement_unit.factor())
+    }
+
     /// Encode a coordinate expressed on this axis.
     ///
     /// # Parameters
@@ -290,7 +335,7 @@ impl Axis {
     ///  * `val`:
     ///      The coordinate to encode. It must be defined as a
     ///      coordinate on this axis.
-    pub fn encode(&self, val: f64) -> Result<Coordinate, String> {
+    pub fn encode(&self, val: f64) -> Result<Coordinate, Error> {
         let max = self.graduation.maximum;
         let min = self.graduation.minimum;
 
@@ -298,12 +343,12 @@ impl Axis {
 
         // Ensure it is within allowed range: Upper bound.
         if d > max {
-            return Err(format!("encode: position out of bounds: {} >= {}", d, max));
+            return Err(Error::OutOfBounds(format!("encode: {} >= {}", d, max)));
         }
 
         // Ensure it is within allowed range: Lower bound.
         if d < min {
-            return Err(format!("encode: position out of bounds: {} < {}", d, min));
+            return Err(Error::OutOfBounds(format!("encode: {} < {}", d, min)));
         }
 
         // Shift range to zero.
@@ -323,7 +368,7 @@ impl Axis {
     ///  * `val`:
     ///      The coordinate to decode. It must be defined as an encoded
     ///      coordinate on this axis.
-    pub fn decode(&self, val: &Coordinate) -> Result<f64, String> {
+    pub fn decode(&self, val: &Coordinate) -> Result<f64, Error> {
         let max = self.graduation.maximum;
         let min = self.graduation.minimum;
 
@@ -338,12 +383,12 @@ impl Axis {
 
         // Ensure it is within allowed range: Upper bound.
         if d > max {
-            return Err(format!("Decode: position out of bounds: {} >= {}", d, max));
+            return Err(Error::OutOfBounds(format!("decode: {} >= {}", d, max)));
         }
 
         // Ensure it is within allowed range: Lower bound.
         if d < min {
-            return Err(format!("Decode: position out of bounds: {} < {}", d, min));
+            return Err(Error::OutOfBounds(format!("decode: {} < {}", d, min)));
         }
 
         Ok(d)
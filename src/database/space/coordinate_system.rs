@@ -5,6 +5,7 @@ use super::axis::Axis;
 use super::coordinate::Coordinate;
 use super::position::Position;
 use super::MAX_K;
+use crate::Error;
 
 /// Kinds of space coordinate systems, or bases
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -128,7 +129,7 @@ impl CoordinateSystem {
     /// # Return value
     ///
     /// The encoded coordinates within this coordinate system.
-    pub fn rebase(&self, position: &Position) -> Result<Position, String> {
+    pub fn rebase(&self, position: &Position) -> Result<Position, Error> {
         match self {
             CoordinateSystem::Universe { origin } => {
                 // Ensure the coordinates are encoded into F64 variants of
@@ -162,7 +163,7 @@ impl CoordinateSystem {
     /// # Return value
     ///
     /// The position expressed in Universe decoded coordinates.
-    pub fn absolute_position(&self, position: &Position) -> Result<Position, String> {
+    pub fn absolute_position(&self, position: &Position) -> Result<Position, Error> {
         match self {
             CoordinateSystem::Universe { origin } => {
                 // Ensure the coordinates are encoded into F64 variants of
@@ -172,6 +173,10 @@ impl CoordinateSystem {
                 Ok(origin + position)
             }
             CoordinateSystem::AffineSystem { axes, .. } => {
+                if position.dimensions() != axes.len() {
+                    return Err(Error::DimensionMismatch(axes.len(), position.dimensions()));
+                }
+
                 // Start from the base origin.
                 let mut rebased = self.origin().clone();
 
@@ -186,6 +191,60 @@ impl CoordinateSystem {
         }
     }
 
+    /// Express a vector of the Universe in the coordinate system.
+    ///
+    /// Unlike positions, vectors are neither clipped nor rounded to the
+    /// graduations, so the resulting coordinates are fractional
+    /// encoded steps.
+    ///
+    /// # Parameters
+    ///
+    ///  * `vector`:
+    ///      expressed in the Universe coordinate system.
+    pub fn rebase_vector(&self, vector: &Position) -> Result<Position, Error> {
+        match self {
+            CoordinateSystem::Universe { .. } => Ok(Vec::<f64>::from(vector).into()),
+            CoordinateSystem::AffineSystem { origin, axes } => {
+                if vector.dimensions() != origin.dimensions() {
+                    return Err(Error::DimensionMismatch(
+                        origin.dimensions(),
+                        vector.dimensions(),
+                    ));
+                }
+
+                Ok(axes
+                    .iter()
+                    .map(|axis| axis.project_in_vector(vector))
+                    .collect())
+            }
+        }
+    }
+
+    /// Express a vector of the coordinate system in the Universe.
+    ///
+    /// # Parameters
+    ///
+    ///  * `vector`:
+    ///      expressed as fractional encoded steps in the coordinate
+    ///      system.
+    pub fn absolute_vector(&self, vector: &Position) -> Result<Position, Error> {
+        match self {
+            CoordinateSystem::Universe { .. } => Ok(Vec::<f64>::from(vector).into()),
+            CoordinateSystem::AffineSystem { origin, axes } => {
+                if vector.dimensions() != axes.len() {
+                    return Err(Error::DimensionMismatch(axes.len(), vector.dimensions()));
+                }
+
+                let mut absolute = origin * 0.0;
+                for k in 0..axes.len() {
+                    absolute += axes[k].project_out_vector(vector[k].f64());
+                }
+
+                Ok(absolute)
+            }
+        }
+    }
+
     /// Encode a position expressed in the current coordinate system.
     ///
     /// Each coordinate is encoded individually, and a new `Position`
@@ -199,18 +258,20 @@ impl CoordinateSystem {
     /// # Return value
     ///
     /// The encoded coordinates within this coordinate system.
-    pub fn encode(&self, position: &[f64]) -> Result<Position, String> {
+    pub fn encode(&self, position: &[f64]) -> Result<Position, Error> {
         let mut encoded = vec![];
 
+        if position.len() != self.dimensions() {
+            return Err(Error::DimensionMismatch(self.dimensions(), position.len()));
+        }
+
         match self {
             CoordinateSystem::Universe { .. } => {
-                assert_eq!(position.len(), MAX_K);
                 for c in position {
                     encoded.push(Coordinate::CoordinateF64(*c));
                 }
             }
             CoordinateSystem::AffineSystem { axes, .. } => {
-                assert_eq!(position.len(), axes.len());
                 for k in 0..axes.len() {
                     encoded.push(axes[k].encode(position[k])?);
                 }
@@ -234,18 +295,23 @@ impl CoordinateSystem {
     /// # Return value
     ///
     /// The decoded coordinates within this coordinate system.
-    pub fn decode(&self, position: &Position) -> Result<Vec<f64>, String> {
+    pub fn decode(&self, position: &Position) -> Result<Vec<f64>, Error> {
         let mut decoded = vec![];
 
+        if position.dimensions() != self.dimensions() {
+            return Err(Error::DimensionMismatch(
+                self.dimensions(),
+                position.dimensions(),
+            ));
+        }
+
         match self {
             CoordinateSystem::Universe { .. } => {
-                assert_eq!(position.dimensions(), MAX_K);
                 for c in 0..position.dimensions() {
                     decoded.push(position[c].into());
                 }
             }
             CoordinateSystem::AffineSystem { axes, .. } => {
-                assert_eq!(position.dimensions(), axes.len());
                 for k in 0..axes.len() {
                     decoded.push(axes[k].decode(&position[k])?);
                 }
@@ -7,6 +7,7 @@ mod coordinate;
 mod coordinate_system;
 mod position;
 mod shape;
+mod voxel_mask;
 
 #[cfg(test)]
 mod tests;
@@ -20,7 +21,11 @@ pub use axis::NumberSet;
 pub use coordinate::Coordinate;
 pub use coordinate_system::CoordinateSystem;
 pub use position::Position;
+pub use shape::Cells;
 pub use shape::Shape;
+pub use voxel_mask::VoxelMask;
+
+use crate::Error;
 
 // Maximum number of dimensions currently supported.
 //
@@ -87,10 +92,82 @@ impl Space {
     ///
     ///  *  `to`:
     ///      Target space in which `position` should be expressed.
-    pub fn change_base(position: &Position, from: &Space, to: &Space) -> Result<Position, String> {
+    pub fn change_base(position: &Position, from: &Space, to: &Space) -> Result<Position, Error> {
         to.rebase(&from.absolute_position(position)?)
     }
 
+    /// Transform a position from space `from` into a position in space
+    /// `to`, unless it lies outside of `to`.
+    ///
+    /// Unlike [change_base](#method.change_base), which clips positions
+    /// to the bounds of `to`, this returns `None` for positions more
+    /// than half a step beyond them.
+    ///
+    /// # Parameters
+    ///
+    ///  * `position`:
+    ///      Position to transform, expressed as encoded coordinates.
+    ///
+    ///  *  `from`:
+    ///      Space in which `position` is defined.
+    ///
+    ///  *  `to`:
+    ///      Target space in which `position` should be expressed.
+    pub fn change_base_within(
+        position: &Position,
+        from: &Space,
+        to: &Space,
+    ) -> Result<Option<Position>, Error> {
+        let absolute = from.absolute_position(position)?;
+
+        if let CoordinateSystem::AffineSystem { origin, axes } = &to.system {
+            let offset = Vec::<f64>::from(&absolute)
+                .iter()
+                .zip(Vec::<f64>::from(origin).iter())
+                .map(|(p, o)| p - o)
+                .collect::<Vec<_>>();
+            let steps = to.system.rebase_vector(&offset.into())?;
+
+            for k in 0..axes.len() {
+                let graduation = axes[k].graduation();
+                let step = steps[k].f64() * graduation.epsilon;
+                let margin = graduation.epsilon / 2.0;
+
+                if step < graduation.minimum - margin || step > graduation.maximum + margin {
+                    return Ok(None);
+                }
+            }
+        }
+
+        Ok(Some(to.rebase(&absolute)?))
+    }
+
+    /// Transform a vector, such as the difference between two
+    /// positions, from space `from` into a vector in space `to`.
+    ///
+    /// Unlike positions, vectors are neither clipped nor rounded to the
+    /// graduations, so their length is preserved.
+    ///
+    /// # Parameters
+    ///
+    ///  * `vector`:
+    ///      Vector to transform, expressed in encoded steps, which may
+    ///      be fractional.
+    ///
+    ///  *  `from`:
+    ///      Space in which `vector` is defined.
+    ///
+    ///  *  `to`:
+    ///      Target space in which `vector` should be expressed.
+    pub fn change_base_vector(
+        vector: &Position,
+        from: &Space,
+        to: &Space,
+    ) -> Result<Position, Error> {
+        to.system
+            .rebase_vector(&from.system.absolute_vector(vector)?)
+    }
+
     /// Id of the reference space.
     pub fn name(&self) -> &String {
         &self.name
@@ -106,6 +183,11 @@ impl Space {
         self.system.axes()
     }
 
+    /// Number of dimensions of the space.
+    pub fn dimensions(&self) -> usize {
+        self.system.dimensions()
+    }
+
     /// Returns the bounding box enclosing the whole space.
     pub fn bounding_box(&self) -> (Position, Position) {
         self.system.bounding_box()
@@ -118,13 +200,13 @@ impl Space {
 
     // `position` is expressed in the Universe, this return encoded
     // coordinates in the current space.
-    fn rebase(&self, position: &Position) -> Result<Position, String> {
+    fn rebase(&self, position: &Position) -> Result<Position, Error> {
         self.system.rebase(position)
     }
 
     // The position is expressed in encoded coordinates in the current space,
     // return an absolute position in Universe.
-    fn absolute_position(&self, position: &Position) -> Result<Position, String> {
+    fn absolute_position(&self, position: &Position) -> Result<Position, Error> {
         self.system.absolute_position(position)
     }
 
@@ -139,7 +221,7 @@ impl Space {
     /// # Return value
     ///
     /// The decoded position within the space.
-    pub fn decode(&self, position: &Position) -> Result<Vec<f64>, String> {
+    pub fn decode(&self, position: &Position) -> Result<Vec<f64>, Error> {
         self.system.decode(position)
     }
 
@@ -154,7 +236,7 @@ impl Space {
     /// # Return value
     ///
     /// The encoded coordinates within the space.
-    pub fn encode(&self, position: &[f64]) -> Result<Position, String> {
+    pub fn encode(&self, position: &[f64]) -> Result<Position, Error> {
         self.system.encode(position)
     }
 }
@@ -2,21 +2,45 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use super::Coordinate;
+use super::CoordinateSystem;
 use super::Position;
 use super::Space;
+use super::VoxelMask;
+use crate::Error;
 
 /// Known shapes descriptions
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum Shape {
     /// A singular point in space.
     Point(Position),
-    //HyperRectangle([Position; MAX_K]),
+    /// Hyperrectangle of any orientation, defined by one of its corners
+    /// and one edge vector per dimension, starting from that corner.
+    HyperRectangle(Position, Vec<Position>),
     /// A sphere in space.
     HyperSphere(Position, Coordinate),
+    /// Ellipsoid of any orientation, defined by its center and one
+    /// semi-axis vector per dimension.
+    Ellipsoid(Position, Vec<Position>),
+    /// Sphere swept along a polyline, such as an electrode track or a
+    /// fibre, defined by the vertices of the polyline and the radius.
+    Capsule(Vec<Position>, Coordinate),
 
     /// Hyperrectangle whose faces have one of the axis as a normal.
     BoundingBox(Position, Position),
-    //Nifti(nifti_data??),
+    /// Dense grid of voxels, such as a NIfTI label volume.
+    VoxelMask(VoxelMask),
+
+    /// Closed triangle mesh in a three-dimensional space, defined by its
+    /// vertices, and its faces as triplets of offsets into the vertices.
+    Mesh(Vec<Position>, Vec<[usize; 3]>),
+
+    /// Positions contained in any of the shapes.
+    Union(Vec<Shape>),
+    /// Positions contained in all of the shapes, if there is at least
+    /// one.
+    Intersection(Vec<Shape>),
+    /// Positions contained in the first shape, but not in the second.
+    Difference(Box<Shape>, Box<Shape>),
 }
 
 impl Shape {
@@ -25,6 +49,11 @@ impl Shape {
     /// The resulting shape is expressed in encoded coordinates in the
     /// target space.
     ///
+    /// When the axes of the spaces have different scales, hyperspheres
+    /// become ellipsoids. Capsules keep a spherical section, whose radius
+    /// becomes the longest semi-axis of the image of the section, so they
+    /// cover at least the same volume, but may select more positions.
+    ///
     /// # Parameters
     ///
     ///  * `from`:
@@ -32,24 +61,75 @@ impl Shape {
     ///
     ///  * `to`:
     ///     Target reference space.
-    pub fn rebase(&self, from: &Space, to: &Space) -> Result<Shape, String> {
+    pub fn rebase(&self, from: &Space, to: &Space) -> Result<Shape, Error> {
         match self {
             Shape::Point(position) => Ok(Shape::Point(Space::change_base(position, from, to)?)),
-            Shape::HyperSphere(center, radius) => {
-                //FIXME: Is the length properly dealt with? How do we process this for space conversions?
-                let mut r = Vec::with_capacity(center.dimensions());
-                for _ in 0..center.dimensions() {
-                    r.push(radius.clone());
-                }
-                let r = r.into();
-                let r = from.absolute_position(&r)?;
-                let r = to.rebase(&(r))?[0];
-                Ok(Shape::HyperSphere(Space::change_base(center, from, to)?, r))
-            }
+            Shape::HyperSphere(center, radius) => Shape::transform_sphere(
+                Space::change_base(center, from, to)?,
+                radius.f64(),
+                |radius| Space::change_base_vector(radius, from, to),
+            ),
+            Shape::Capsule(vertices, radius) => Ok(Shape::Capsule(
+                vertices
+                    .iter()
+                    .map(|vertex| Space::change_base(vertex, from, to))
+                    .collect::<Result<_, _>>()?,
+                Shape::transform_radius(from.dimensions(), radius.f64(), |radius| {
+                    Space::change_base_vector(radius, from, to)
+                })?
+                .into(),
+            )),
+            Shape::Ellipsoid(center, semi_axes) => Ok(Shape::Ellipsoid(
+                Space::change_base(center, from, to)?,
+                semi_axes
+                    .iter()
+                    .map(|semi_axis| Space::change_base_vector(semi_axis, from, to))
+                    .collect::<Result<_, _>>()?,
+            )),
             Shape::BoundingBox(lower, higher) => Ok(Shape::BoundingBox(
                 Space::change_base(lower, from, to)?,
                 Space::change_base(higher, from, to)?,
             )),
+            Shape::HyperRectangle(corner, edges) => Ok(Shape::HyperRectangle(
+                Space::change_base(corner, from, to)?,
+                edges
+                    .iter()
+                    .map(|edge| Space::change_base_vector(edge, from, to))
+                    .collect::<Result<_, _>>()?,
+            )),
+            Shape::Mesh(vertices, faces) => {
+                Shape::check_mesh(vertices, faces, from.dimensions())?;
+                Shape::check_mesh(vertices, faces, to.dimensions())?;
+
+                Ok(Shape::Mesh(
+                    vertices
+                        .iter()
+                        .map(|vertex| Space::change_base(vertex, from, to))
+                        .collect::<Result<_, _>>()?,
+                    faces.clone(),
+                ))
+            }
+            Shape::VoxelMask(mask) => {
+                Ok(Shape::VoxelMask(mask.transform(|position| {
+                    Space::change_base(position, from, to)
+                })?))
+            }
+            Shape::Union(shapes) => Ok(Shape::Union(
+                shapes
+                    .iter()
+                    .map(|shape| shape.rebase(from, to))
+                    .collect::<Result<_, _>>()?,
+            )),
+            Shape::Intersection(shapes) => Ok(Shape::Intersection(
+                shapes
+                    .iter()
+                    .map(|shape| shape.rebase(from, to))
+                    .collect::<Result<_, _>>()?,
+            )),
+            Shape::Difference(shape, removed) => Ok(Shape::Difference(
+                Box::new(shape.rebase(from, to)?),
+                Box::new(removed.rebase(from, to)?),
+            )),
         }
     }
 
@@ -66,17 +146,70 @@ impl Shape {
     ///
     /// # Return value
     ///
-    /// The shape with decoded positions within the space.
-    pub fn decode(&self, space: &Space) -> Result<Shape, String> {
+    /// The shape with decoded positions within the space. As for
+    /// [rebase](#method.rebase), the radius of capsules is widened when
+    /// the axes have different scales.
+    pub fn decode(&self, space: &Space) -> Result<Shape, Error> {
         let s = match self {
             Shape::Point(position) => Shape::Point(space.decode(position)?.into()),
             Shape::HyperSphere(center, radius) => {
-                //FIXME: Is the length properly dealt with? How do we process this for space conversions?
-                Shape::HyperSphere(space.decode(center)?.into(), *radius)
+                Shape::transform_sphere(space.decode(center)?.into(), radius.f64(), |radius| {
+                    Shape::decode_vector(space, radius)
+                })?
+            }
+            Shape::Capsule(vertices, radius) => {
+                let mut decoded = Vec::with_capacity(vertices.len());
+                for vertex in vertices {
+                    decoded.push(space.decode(vertex)?.into());
+                }
+                let radius = Shape::transform_radius(space.dimensions(), radius.f64(), |radius| {
+                    Shape::decode_vector(space, radius)
+                })?;
+                Shape::Capsule(decoded, radius.into())
             }
+            Shape::Ellipsoid(center, semi_axes) => Shape::Ellipsoid(
+                space.decode(center)?.into(),
+                semi_axes
+                    .iter()
+                    .map(|semi_axis| Shape::decode_vector(space, semi_axis))
+                    .collect::<Result<_, _>>()?,
+            ),
             Shape::BoundingBox(lower, higher) => {
                 Shape::BoundingBox(space.decode(lower)?.into(), space.decode(higher)?.into())
             }
+            Shape::HyperRectangle(corner, edges) => Shape::HyperRectangle(
+                space.decode(corner)?.into(),
+                edges
+                    .iter()
+                    .map(|edge| Shape::decode_vector(space, edge))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Shape::Mesh(vertices, faces) => {
+                let mut decoded = Vec::with_capacity(vertices.len());
+                for vertex in vertices {
+                    decoded.push(space.decode(vertex)?.into());
+                }
+                Shape::Mesh(decoded, faces.clone())
+            }
+            Shape::VoxelMask(mask) => {
+                Shape::VoxelMask(mask.transform(|position| Ok(space.decode(position)?.into()))?)
+            }
+            Shape::Union(shapes) => Shape::Union(
+                shapes
+                    .iter()
+                    .map(|shape| shape.decode(space))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Shape::Intersection(shapes) => Shape::Intersection(
+                shapes
+                    .iter()
+                    .map(|shape| shape.decode(space))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Shape::Difference(shape, removed) => Shape::Difference(
+                Box::new(shape.decode(space)?),
+                Box::new(removed.decode(space)?),
+            ),
         };
 
         Ok(s)
@@ -94,8 +227,10 @@ impl Shape {
     ///
     /// # Return value
     ///
-    /// The shape with encoded coordinates within the space.
-    pub fn encode(&self, space: &Space) -> Result<Shape, String> {
+    /// The shape with encoded coordinates within the space. As for
+    /// [rebase](#method.rebase), the radius of capsules is widened when
+    /// the axes have different scales.
+    pub fn encode(&self, space: &Space) -> Result<Shape, Error> {
         let s = match self {
             Shape::Point(position) => {
                 let p: Vec<f64> = position.into();
@@ -103,39 +238,334 @@ impl Shape {
             }
             Shape::HyperSphere(center, radius) => {
                 let p: Vec<f64> = center.into();
-                //FIXME: Is the length properly dealt with? How do we process this for space conversions?
-                Shape::HyperSphere(space.encode(&p)?, *radius)
+                Shape::transform_sphere(space.encode(&p)?, radius.f64(), |radius| {
+                    Shape::encode_vector(space, radius)
+                })?
+            }
+            Shape::Capsule(vertices, radius) => {
+                let mut encoded = Vec::with_capacity(vertices.len());
+                for vertex in vertices {
+                    let p: Vec<f64> = vertex.into();
+                    encoded.push(space.encode(&p)?);
+                }
+                let radius = Shape::transform_radius(space.dimensions(), radius.f64(), |radius| {
+                    Shape::encode_vector(space, radius)
+                })?;
+                Shape::Capsule(encoded, radius.into())
+            }
+            Shape::Ellipsoid(center, semi_axes) => {
+                let p: Vec<f64> = center.into();
+                Shape::Ellipsoid(
+                    space.encode(&p)?,
+                    semi_axes
+                        .iter()
+                        .map(|semi_axis| Shape::encode_vector(space, semi_axis))
+                        .collect::<Result<_, _>>()?,
+                )
             }
             Shape::BoundingBox(lower, higher) => {
                 let lower: Vec<f64> = lower.into();
                 let higher: Vec<f64> = higher.into();
                 Shape::BoundingBox(space.encode(&lower)?, space.encode(&higher)?)
             }
+            Shape::HyperRectangle(corner, edges) => {
+                let p: Vec<f64> = corner.into();
+                Shape::HyperRectangle(
+                    space.encode(&p)?,
+                    edges
+                        .iter()
+                        .map(|edge| Shape::encode_vector(space, edge))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            Shape::Mesh(vertices, faces) => {
+                Shape::check_mesh(vertices, faces, space.dimensions())?;
+
+                let mut encoded = Vec::with_capacity(vertices.len());
+                for vertex in vertices {
+                    let p: Vec<f64> = vertex.into();
+                    encoded.push(space.encode(&p)?);
+                }
+                Shape::Mesh(encoded, faces.clone())
+            }
+            Shape::VoxelMask(mask) => Shape::VoxelMask(mask.transform(|position| {
+                let p: Vec<f64> = position.into();
+                space.encode(&p)
+            })?),
+            Shape::Union(shapes) => Shape::Union(
+                shapes
+                    .iter()
+                    .map(|shape| shape.encode(space))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Shape::Intersection(shapes) => Shape::Intersection(
+                shapes
+                    .iter()
+                    .map(|shape| shape.encode(space))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Shape::Difference(shape, removed) => Shape::Difference(
+                Box::new(shape.encode(space)?),
+                Box::new(removed.encode(space)?),
+            ),
         };
 
         Ok(s)
     }
 
+    // Decode a vector, such as an edge, expressed in encoded steps in
+    // `space`. Vectors are only scaled, and not shifted.
+    fn decode_vector(space: &Space, vector: &Position) -> Result<Position, Error> {
+        if vector.dimensions() != space.dimensions() {
+            return Err(Error::DimensionMismatch(
+                space.dimensions(),
+                vector.dimensions(),
+            ));
+        }
+
+        match &space.system {
+            // Coordinates are not encoded in the Universe.
+            CoordinateSystem::Universe { .. } => Ok(Vec::<f64>::from(vector).into()),
+            CoordinateSystem::AffineSystem { axes, .. } => Ok((0..axes.len())
+                .map(|k| vector[k].f64() * axes[k].graduation().epsilon)
+                .collect()),
+        }
+    }
+
+    // Encode a vector, such as an edge, expressed in `space`. Vectors are
+    // only scaled, and not rounded to the graduation.
+    fn encode_vector(space: &Space, vector: &Position) -> Result<Position, Error> {
+        if vector.dimensions() != space.dimensions() {
+            return Err(Error::DimensionMismatch(
+                space.dimensions(),
+                vector.dimensions(),
+            ));
+        }
+
+        match &space.system {
+            // Coordinates are not encoded in the Universe.
+            CoordinateSystem::Universe { .. } => Ok(Vec::<f64>::from(vector).into()),
+            CoordinateSystem::AffineSystem { axes, .. } => Ok((0..axes.len())
+                .map(|k| vector[k].f64() / axes[k].graduation().epsilon)
+                .collect()),
+        }
+    }
+
+    // Image of an hypersphere, whose center has already been transformed,
+    // through the linear transformation `f` of its radii.
+    //
+    // This remains an hypersphere if the radii stay orthogonal and of the
+    // same length, otherwise this is an ellipsoid, which is the case
+    // when the axes of the spaces have different scales.
+    fn transform_sphere<F>(center: Position, radius: f64, f: F) -> Result<Shape, Error>
+    where
+        F: Fn(&Position) -> Result<Position, Error>,
+    {
+        // Relative tolerance on the lengths and angles of the radii.
+        const EPSILON: f64 = 1e-9;
+
+        let dimensions = center.dimensions();
+        let semi_axes = (0..dimensions)
+            .map(|k| {
+                let mut radius_k = vec![0.0; dimensions];
+                radius_k[k] = radius;
+                f(&radius_k.into())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let length = semi_axes
+            .first()
+            .map_or(radius, |semi_axis| semi_axis.norm());
+        let isotropic = semi_axes.iter().enumerate().all(|(i, a)| {
+            (a.norm() - length).abs() <= EPSILON * length
+                && semi_axes[i + 1..]
+                    .iter()
+                    .all(|b| a.dot_product(b).abs() <= EPSILON * length * length)
+        });
+
+        if isotropic {
+            Ok(Shape::HyperSphere(center, length.into()))
+        } else {
+            Ok(Shape::Ellipsoid(center, semi_axes))
+        }
+    }
+
+    // Radius of a swept sphere after the linear transformation `f` of its
+    // radii, in `dimensions` dimensions.
+    //
+    // When the axes of the spaces have different scales, the sphere
+    // becomes an ellipsoid, so the longest semi-axis is used, to cover at
+    // least the same volume.
+    fn transform_radius<F>(dimensions: usize, radius: f64, f: F) -> Result<f64, Error>
+    where
+        F: Fn(&Position) -> Result<Position, Error>,
+    {
+        let mut length = 0.0;
+        for k in 0..dimensions {
+            let mut radius_k = vec![0.0; dimensions];
+            radius_k[k] = radius;
+            length = f(&radius_k.into())?.norm().max(length);
+        }
+
+        Ok(length)
+    }
+
+    // Distance between `position` and the segment [`a`, `b`].
+    fn segment_distance(a: &Position, b: &Position, position: &Position) -> f64 {
+        // Compute with floating point values, as the difference of encoded
+        // coordinates would be clipped to zero.
+        let (a, b, p): (Vec<f64>, Vec<f64>, Vec<f64>) = (a.into(), b.into(), position.into());
+        let dot = |u: &[f64], v: &[f64]| u.iter().zip(v).map(|(u, v)| u * v).sum::<f64>();
+
+        let ab = b.iter().zip(&a).map(|(b, a)| b - a).collect::<Vec<_>>();
+        let ap = p.iter().zip(&a).map(|(p, a)| p - a).collect::<Vec<_>>();
+        let length = dot(&ab, &ab);
+
+        let t = if length > 0.0 {
+            (dot(&ap, &ab) / length).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        ap.iter()
+            .zip(&ab)
+            .map(|(ap, ab)| (ap - ab * t) * (ap - ab * t))
+            .sum::<f64>()
+            .sqrt()
+    }
+
     /// Compute the minimum bounding box of the shape.
     ///
     /// This is an hyperrectangle whose faces are perpendicular to an
     /// axis of the space, and which minimally covers the shape.
+    ///
+    /// The bounding box of an intersection is the intersection of the
+    /// bounding boxes of its shapes, which is empty, with its higher
+    /// corner lower than its lower one along some axis, when they do not
+    /// overlap. Empty unions and intersections have a bounding box
+    /// without dimensions.
     pub fn get_mbb(&self) -> (Position, Position) {
         match self {
             Shape::Point(position) => (position.clone(), position.clone()),
             Shape::HyperSphere(center, radius) => {
-                let dimensions = center.dimensions();
-                let mut vr = Vec::with_capacity(dimensions);
-                for _ in 0..dimensions {
-                    vr.push(*radius);
-                }
-                let vr: &Position = &vr.into();
-                (center - vr, center + vr)
+                // Compute with floating point values, as the difference of
+                // encoded coordinates would be clipped to zero.
+                let center: Vec<f64> = center.into();
+                let radius = radius.f64();
+                (
+                    center.iter().map(|c| c - radius).collect::<Vec<_>>().into(),
+                    center.iter().map(|c| c + radius).collect::<Vec<_>>().into(),
+                )
             }
             Shape::BoundingBox(lower, higher) => (lower.clone(), higher.clone()),
+            Shape::Capsule(vertices, radius) => {
+                let mut vertices = vertices.iter().map(Vec::<f64>::from);
+                let (lower, higher) = match vertices.next() {
+                    None => return (Position::PositionN(vec![]), Position::PositionN(vec![])),
+                    Some(vertex) => vertices.fold((vertex.clone(), vertex), |(l, h), v| {
+                        (
+                            l.iter().zip(&v).map(|(l, v)| l.min(*v)).collect(),
+                            h.iter().zip(&v).map(|(h, v)| h.max(*v)).collect(),
+                        )
+                    }),
+                };
+
+                let radius = radius.f64();
+                (
+                    lower.iter().map(|l| l - radius).collect(),
+                    higher.iter().map(|h| h + radius).collect(),
+                )
+            }
+            Shape::Ellipsoid(center, semi_axes) => {
+                // Along each dimension, the ellipsoid extends by the norm of
+                // the components of the semi-axes.
+                let center: Vec<f64> = center.into();
+                let (lower, higher): (Vec<_>, Vec<_>) = center
+                    .iter()
+                    .enumerate()
+                    .map(|(k, c)| {
+                        let extent = semi_axes
+                            .iter()
+                            .filter(|semi_axis| semi_axis.dimensions() > k)
+                            .map(|semi_axis| semi_axis[k].f64().powi(2))
+                            .sum::<f64>()
+                            .sqrt();
+                        (c - extent, c + extent)
+                    })
+                    .unzip();
+                (lower.into(), higher.into())
+            }
+            Shape::HyperRectangle(corner, edges) => {
+                // Each edge extends the box on one side, per dimension.
+                let mut lower: Vec<f64> = corner.into();
+                let mut higher = lower.clone();
+                for edge in edges {
+                    for k in 0..lower.len().min(edge.dimensions()) {
+                        let length = edge[k].f64();
+                        if length < 0.0 {
+                            lower[k] += length;
+                        } else {
+                            higher[k] += length;
+                        }
+                    }
+                }
+                (lower.into(), higher.into())
+            }
+            Shape::Mesh(vertices, _) => {
+                let mut vertices = vertices.iter().map(Vec::<f64>::from);
+                match vertices.next() {
+                    None => (Position::PositionN(vec![]), Position::PositionN(vec![])),
+                    Some(vertex) => {
+                        let (lower, higher) =
+                            vertices.fold((vertex.clone(), vertex), |(l, h), v| {
+                                (
+                                    l.iter().zip(&v).map(|(l, v)| l.min(*v)).collect::<Vec<_>>(),
+                                    h.iter().zip(&v).map(|(h, v)| h.max(*v)).collect::<Vec<_>>(),
+                                )
+                            });
+                        (lower.into(), higher.into())
+                    }
+                }
+            }
+            Shape::VoxelMask(mask) => mask.get_mbb(),
+            Shape::Union(shapes) | Shape::Intersection(shapes) => {
+                let union = matches!(self, Shape::Union(_));
+                let mut boxes = shapes.iter().map(|shape| shape.get_mbb());
+
+                let (lower, higher) = match boxes.next() {
+                    None => return (Position::PositionN(vec![]), Position::PositionN(vec![])),
+                    Some((lower, higher)) => (Vec::<f64>::from(lower), Vec::<f64>::from(higher)),
+                };
+
+                let (lower, higher) = boxes.fold((lower, higher), |(lower, higher), (l, h)| {
+                    let (l, h) = (Vec::<f64>::from(l), Vec::<f64>::from(h));
+                    lower
+                        .iter()
+                        .zip(&higher)
+                        .zip(l.iter().zip(&h))
+                        .map(|((&lower, &higher), (&l, &h))| {
+                            if union {
+                                (lower.min(l), higher.max(h))
+                            } else {
+                                (lower.max(l), higher.min(h))
+                            }
+                        })
+                        .unzip()
+                });
+
+                (lower.into(), higher.into())
+            }
+            Shape::Difference(shape, _) => shape.get_mbb(),
         }
     }
 
+    /// Check if the minimum bounding box of the shape is empty.
+    pub fn is_mbb_empty(&self) -> bool {
+        let (lower, higher) = self.get_mbb();
+
+        lower.dimensions() == 0 || (0..lower.dimensions()).any(|k| higher[k].f64() < lower[k].f64())
+    }
+
     /// Check if the shape overlaps with the given position.
     ///
     /// # Parameters
@@ -145,112 +575,278 @@ impl Shape {
     pub fn contains(&self, position: &Position) -> bool {
         match self {
             Shape::Point(reference) => reference == position,
-            Shape::HyperSphere(center, radius) => (position - center).norm() <= radius.f64(),
+            Shape::HyperSphere(center, radius) => {
+                Shape::segment_distance(center, center, position) <= radius.f64()
+            }
+            Shape::Capsule(vertices, radius) => match vertices.as_slice() {
+                [] => false,
+                [center] => Shape::segment_distance(center, center, position) <= radius.f64(),
+                vertices => vertices
+                    .windows(2)
+                    .any(|s| Shape::segment_distance(&s[0], &s[1], position) <= radius.f64()),
+            },
             Shape::BoundingBox(lower, higher) => lower <= position && position <= higher,
+            Shape::Ellipsoid(center, semi_axes) => {
+                // Tolerance on the relative distance to the center.
+                const EPSILON: f64 = 1e-9;
+
+                matches!(
+                    Shape::base_coordinates(center, semi_axes, position),
+                    Some(t) if t.iter().map(|t| t * t).sum::<f64>() <= 1.0 + EPSILON
+                )
+            }
+            // Positions on the faces are considered inside.
+            Shape::HyperRectangle(corner, edges) => {
+                // Tolerance on the relative coordinates along the edges.
+                const EPSILON: f64 = 1e-9;
+
+                matches!(
+                    Shape::base_coordinates(corner, edges, position),
+                    Some(t) if t.iter().all(|&t| (-EPSILON..=1.0 + EPSILON).contains(&t))
+                )
+            }
+            // Positions on the surface are considered inside, whatever
+            // the orientation of the faces.
+            Shape::Mesh(vertices, faces) => matches!(
+                Shape::winding_number(vertices, faces, position),
+                Some(w) if w.abs() >= 0.5
+            ),
+            Shape::VoxelMask(mask) => mask.contains(position),
+            Shape::Union(shapes) => shapes.iter().any(|shape| shape.contains(position)),
+            Shape::Intersection(shapes) => {
+                !shapes.is_empty() && shapes.iter().all(|shape| shape.contains(position))
+            }
+            Shape::Difference(shape, removed) => {
+                shape.contains(position) && !removed.contains(position)
+            }
         }
     }
 
-    /* Original version proposed by Charles François Rey - 2019
-    ```perl
-    use strict;
-
-    my $conf = [[0, 2], [1, 3], [11, 20], [5, 6]];
-    my $dim = scalar @{$conf};
-
-    sub nxt {
-        my ($state) = @_;
-        foreach my $i (0..$dim-1) {
-            $i = $dim-1-$i;
-            $state->[$i] = $state->[$i] + 1;
-            if ($state->[$i] > $conf->[$i]->[-1]) {
-                $state->[$i] = $conf->[$i]->[0];
-                # => carry
-            } else {
-                return 1;
+    // Coordinates of `position` in the base formed by `vectors`, with
+    // `origin` as origin. An hyperrectangle is the unit hypercube in the
+    // base of its edges, and an ellipsoid the unit hypersphere in the
+    // base of its semi-axes.
+    //
+    // Returns `None` if there is not one vector per dimension, or if they
+    // are linearly dependent.
+    fn base_coordinates(
+        origin: &Position,
+        vectors: &[Position],
+        position: &Position,
+    ) -> Option<Vec<f64>> {
+        let n = position.dimensions();
+        if origin.dimensions() != n
+            || vectors.len() != n
+            || vectors.iter().any(|vector| vector.dimensions() != n)
+        {
+            return None;
+        }
+
+        // Solve `vectors * t = position - origin` by Gaussian elimination,
+        // with the vectors as columns of the augmented matrix.
+        let mut m = (0..n)
+            .map(|r| {
+                let mut row = vectors
+                    .iter()
+                    .map(|vector| vector[r].f64())
+                    .collect::<Vec<_>>();
+                row.push(position[r].f64() - origin[r].f64());
+                row
+            })
+            .collect::<Vec<_>>();
+
+        for c in 0..n {
+            let pivot = (c..n).max_by(|&a, &b| m[a][c].abs().total_cmp(&m[b][c].abs()))?;
+            if m[pivot][c] == 0.0 {
+                return None;
+            }
+            m.swap(c, pivot);
+
+            let pivot = m[c].clone();
+            for (r, row) in m.iter_mut().enumerate() {
+                if r != c {
+                    let factor = row[c] / pivot[c];
+                    for (value, p) in row.iter_mut().zip(&pivot).skip(c) {
+                        *value -= factor * p;
+                    }
+                }
             }
         }
-        return;
-    }
 
-    sub pretty {
-        my ($state) = @_;
-        return "(", join(', ', @{$state}), ")";
+        Some((0..n).map(|r| m[r][n] / m[r][r]).collect())
     }
 
-    sub first {
-        return [ map { $_->[0] } @{$conf} ];
+    // Check that a mesh is defined in a three-dimensional space of
+    // `dimensions` dimensions, with at least one face, whose offsets refer
+    // to its vertices.
+    fn check_mesh(
+        vertices: &[Position],
+        faces: &[[usize; 3]],
+        dimensions: usize,
+    ) -> Result<(), Error> {
+        if dimensions != 3 {
+            return Err(Error::InvalidDefinition(format!(
+                "Meshes are only supported in three dimensions, found {}",
+                dimensions
+            )));
+        }
+
+        if let Some(vertex) = vertices.iter().find(|vertex| vertex.dimensions() != 3) {
+            return Err(Error::DimensionMismatch(3, vertex.dimensions()));
+        }
+
+        if faces.is_empty() {
+            return Err(Error::InvalidDefinition("Mesh has no faces".to_string()));
+        }
+
+        match faces.iter().flatten().find(|&&v| v >= vertices.len()) {
+            Some(v) => Err(Error::InvalidDefinition(format!(
+                "Mesh face refers to vertex {}, out of {}",
+                v,
+                vertices.len()
+            ))),
+            None => Ok(()),
+        }
     }
 
-    my $i = 0;
-    my $s = first;
-    do {
-        print $i++, ": ", pretty($s), "\n";
-    } while (nxt($s))
-    ```*/
-    fn gen(lower: &Position, higher: &Position) -> Vec<Position> {
-        fn next(
-            dimensions: usize,
-            lower: &Position,
-            higher: &Position,
-            state: &mut Position,
-        ) -> bool {
-            for i in (0..dimensions).rev() {
-                state[i] = (state[i].u64() + 1).into();
-                if state[i] >= higher[i] {
-                    state[i] = lower[i];
-                // => carry
-                } else {
-                    return true;
-                }
-            }
+    // Generalised winding number of a closed triangle mesh around
+    // `position`, which is 1 inside the mesh when its faces are oriented
+    // outwards, -1 when they are oriented inwards, and 0 outside. It is
+    // computed as the sum of the solid angles of the faces, seen from
+    // `position`, using the formula of Van Oosterom and Strackee. As for
+    // bounding boxes, positions on the surface are inside, so it is 1 on
+    // the faces.
+    //
+    // Returns `None` if the mesh is not three-dimensional, or a face
+    // refers to a missing vertex.
+    fn winding_number(
+        vertices: &[Position],
+        faces: &[[usize; 3]],
+        position: &Position,
+    ) -> Option<f64> {
+        fn dot(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+            a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+        }
 
-            false
+        fn cross(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+            [
+                a[1] * b[2] - a[2] * b[1],
+                a[2] * b[0] - a[0] * b[2],
+                a[0] * b[1] - a[1] * b[0],
+            ]
         }
 
-        fn first(lower: &Position) -> Position {
-            let mut current = vec![];
-            for i in 0..lower.dimensions() {
-                current.push(lower[i].u64());
-            }
+        fn sub(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+            [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+        }
+
+        // Relative tolerance to decide if a position is on a face.
+        const EPSILON: f64 = 1e-9;
 
-            current.into()
+        if position.dimensions() != 3 {
+            return None;
         }
 
-        let mut results = vec![];
+        let mut total = 0.0;
+        for face in faces {
+            let mut corners = [[0.0; 3]; 3];
+            for (corner, &vertex) in corners.iter_mut().zip(face) {
+                let vertex = vertices.get(vertex)?;
+                if vertex.dimensions() != 3 {
+                    return None;
+                }
+                for k in 0..3 {
+                    corner[k] = vertex[k].f64() - position[k].f64();
+                }
+            }
+
+            let [a, b, c] = &corners;
+            let (la, lb, lc) = (dot(a, a).sqrt(), dot(b, b).sqrt(), dot(c, c).sqrt());
+            let det = dot(a, &cross(b, c));
 
-        // Redefine lower as a compacted form of lower for all coordinates.
-        let lower = first(lower);
+            // `position` is the origin, check if it is within the plane
+            // of the face, then within its edges.
+            let normal = cross(&sub(b, a), &sub(c, a));
+            let area = dot(&normal, &normal).sqrt();
+            if area > 0.0
+                && det.abs() <= EPSILON * area * la.max(lb).max(lc)
+                && [(a, b), (b, c), (c, a)]
+                    .iter()
+                    .all(|(u, v)| dot(&normal, &cross(u, v)) >= -EPSILON * area * area)
+            {
+                return Some(1.0);
+            }
 
-        // Initialise the current value
-        let mut current = lower.clone();
+            let div = la * lb * lc + dot(a, b) * lc + dot(b, c) * la + dot(c, a) * lb;
 
-        // Add the first Position to the results, as next will return the following one.
-        results.push(current.clone());
-        while next(lower.dimensions(), &lower, higher, &mut current) {
-            results.push(current.clone())
+            total += 2.0 * det.atan2(div);
         }
-        results
+
+        Some(total / (4.0 * std::f64::consts::PI))
     }
 
     /// Transform a Shape into a list of `Position` which approximate
     /// the shape.
-    // TODO: Return an iterator instead, for performance!
-    pub fn rasterise(&self) -> Result<Vec<Position>, String> {
-        match self {
-            Shape::Point(position) => Ok(vec![position.clone()]),
-            Shape::HyperSphere(center, radius) => {
-                let (lower, higher) = self.get_mbb();
-                let radius = radius.f64();
+    pub fn rasterise(&self) -> Result<Vec<Position>, Error> {
+        Ok(self.rasterise_iter()?.collect())
+    }
 
-                let positions = Shape::gen(&lower, &higher)
-                    .into_iter()
-                    .filter(|p| (p - center).norm() <= radius)
-                    .collect();
+    /// Transform a Shape into a lazy iterator over the `Position`s which
+    /// approximate the shape.
+    ///
+    /// The positions are generated on demand, so that the memory used
+    /// does not depend on the size of the shape.
+    pub fn rasterise_iter(&self) -> Result<Cells<'_>, Error> {
+        let mut cells = Cells {
+            shape: self,
+            point: None,
+            grids: vec![],
+        };
 
-                Ok(positions)
+        match self {
+            Shape::Point(position) => cells.point = Some(position.clone()),
+            Shape::BoundingBox(lower, higher) => cells.grids.push((Grid::new(lower, higher), 0)),
+            Shape::Mesh(vertices, _) if vertices.is_empty() => (),
+            Shape::Capsule(vertices, _) if vertices.is_empty() => (),
+            Shape::Capsule(vertices, radius) if vertices.len() > 2 => {
+                // Rasterise each segment separately, as the bounding box
+                // of the whole polyline might be mostly empty.
+                for (s, segment) in vertices.windows(2).enumerate() {
+                    let segment = Shape::Capsule(segment.to_vec(), *radius);
+                    cells.grids.push((Shape::mbb_grid(&segment), s));
+                }
             }
-            Shape::BoundingBox(lower, higher) => Ok(Shape::gen(lower, higher)),
+            Shape::Union(_) | Shape::Intersection(_) | Shape::Difference(_, _)
+                if self.is_mbb_empty() => {}
+            Shape::HyperSphere(_, _)
+            | Shape::HyperRectangle(_, _)
+            | Shape::Ellipsoid(_, _)
+            | Shape::Capsule(_, _)
+            | Shape::Mesh(_, _)
+            | Shape::VoxelMask(_)
+            | Shape::Union(_)
+            | Shape::Intersection(_)
+            | Shape::Difference(_, _) => cells.grids.push((Shape::mbb_grid(self), 0)),
         }
+
+        // Grids are consumed from the end.
+        cells.grids.reverse();
+
+        Ok(cells)
+    }
+
+    // Grid covering every position of the minimum bounding box of the
+    // shape, included.
+    fn mbb_grid(shape: &Shape) -> Grid {
+        let (lower, higher) = shape.get_mbb();
+        let lower = (0..lower.dimensions())
+            .map(|k| lower[k].f64().max(0.0).floor() as u64)
+            .collect::<Vec<_>>();
+        let higher = (0..higher.dimensions())
+            .map(|k| higher[k].f64().max(0.0).floor() as u64 + 1)
+            .collect::<Vec<_>>();
+
+        Grid::new(&lower.into(), &higher.into())
     }
 
     /// Transform a Shape into a list of `Position` which approximate
@@ -260,20 +856,35 @@ impl Shape {
     ///
     ///  * `space`:
     ///      Reference space in which the shape is expressed.
+    pub fn rasterise_from(&self, space: &Space) -> Result<Vec<Position>, Error> {
+        Ok(self.rasterise_from_iter(space)?.collect())
+    }
+
+    /// Transform a Shape into a lazy iterator over the `Position`s which
+    /// approximate the shape, in absolute, or Universe positions.
     ///
-    // TODO: Return an iterator instead, for performance!
-    pub fn rasterise_from(&self, space: &Space) -> Result<Vec<Position>, String> {
+    /// # Parameters
+    ///
+    ///  * `space`:
+    ///      Reference space in which the shape is expressed.
+    pub fn rasterise_from_iter<'s>(
+        &'s self,
+        space: &'s Space,
+    ) -> Result<impl Iterator<Item = Position> + Clone + 's, Error> {
         Ok(self
-            .rasterise()?
-            .into_iter()
-            .filter_map(|p| match space.absolute_position(&p) {
-                Ok(p) => Some(p),
-                Err(_) => None, // Should be impossible, but let's handle the case.
-            })
-            .collect())
+            .rasterise_iter()?
+            // Errors should be impossible, but let's handle the case.
+            .filter_map(move |p| space.absolute_position(&p).ok()))
     }
 
     /// Compute the volume.
+    ///
+    /// Some shapes have no closed formula: unions, intersections and
+    /// differences of overlapping shapes, and capsules along polylines
+    /// whose segments are short compared to the radius, or close to each
+    /// other. Their volume is estimated by testing the center of each
+    /// cell of a grid of about 32768 cells over their bounding box. This
+    /// is exact for shapes made of bounding boxes only.
     pub fn volume(&self) -> f64 {
         match self {
             Shape::Point(_) => std::f64::EPSILON, // Smallest non-zero volume possible
@@ -316,6 +927,436 @@ impl Shape {
 
                 a * radius.powi(i as i32)
             }
+            Shape::Capsule(vertices, radius) => {
+                let n = vertices.first().map_or(0, |vertex| vertex.dimensions());
+                let ball = match vertices.first() {
+                    None => return 0.0,
+                    Some(center) => Shape::HyperSphere(center.clone(), *radius).volume(),
+                };
+
+                // A single segment is a cylinder capped by two half
+                // spheres, while the segments overlap around the bends.
+                match vertices.as_slice() {
+                    [_] => ball,
+                    [a, b] => {
+                        let section = Shape::ball_volume(n.saturating_sub(1), radius.f64());
+                        ball + section * Shape::segment_distance(a, a, b)
+                    }
+                    _ => Shape::polyline_volume(vertices, radius.f64())
+                        .unwrap_or_else(|| self.sample_volume()),
+                }
+            }
+            Shape::HyperRectangle(_, edges) => Shape::determinant(edges).abs(),
+            Shape::Ellipsoid(center, semi_axes) => {
+                // Volume of the unit hypersphere, scaled by the semi-axes.
+                Shape::HyperSphere(center.clone(), 1.0.into()).volume()
+                    * Shape::determinant(semi_axes).abs()
+            }
+            Shape::VoxelMask(mask) => mask.volume(),
+            Shape::Mesh(vertices, faces) => {
+                // Sum of the signed volumes of the tetrahedra formed by
+                // the origin and each face.
+                let mut volume = 0.0;
+                for face in faces {
+                    let corner = |i: usize| -> Vec<f64> {
+                        vertices
+                            .get(face[i])
+                            .map_or_else(|| vec![0.0; 3], |v| v.into())
+                    };
+                    let (a, b, c) = (corner(0), corner(1), corner(2));
+                    if a.len() != 3 || b.len() != 3 || c.len() != 3 {
+                        continue;
+                    }
+
+                    volume += a[0] * (b[1] * c[2] - b[2] * c[1])
+                        + a[1] * (b[2] * c[0] - b[0] * c[2])
+                        + a[2] * (b[0] * c[1] - b[1] * c[0]);
+                }
+
+                (volume / 6.0).abs()
+            }
+            Shape::Union(shapes) => {
+                // Shapes whose bounding boxes do not overlap can be
+                // summed up.
+                let disjoint = shapes.iter().enumerate().all(|(i, a)| {
+                    shapes[i + 1..]
+                        .iter()
+                        .all(|b| Shape::Intersection(vec![a.clone(), b.clone()]).is_mbb_empty())
+                });
+
+                if disjoint {
+                    shapes.iter().map(|shape| shape.volume()).sum()
+                } else {
+                    self.sample_volume()
+                }
+            }
+            Shape::Intersection(shapes) => match shapes.len() {
+                _ if self.is_mbb_empty() => 0.0,
+                1 => shapes[0].volume(),
+                _ => self.sample_volume(),
+            },
+            Shape::Difference(shape, removed) => {
+                if Shape::Intersection(vec![*shape.clone(), *removed.clone()]).is_mbb_empty() {
+                    shape.volume()
+                } else {
+                    self.sample_volume()
+                }
+            }
+        }
+    }
+
+    // Volume of a ball of `dimensions` dimensions.
+    fn ball_volume(dimensions: usize, radius: f64) -> f64 {
+        match dimensions {
+            0 => 1.0,
+            _ => Shape::HyperSphere(vec![0.0; dimensions].into(), radius.into()).volume(),
+        }
+    }
+
+    // Volume of a capsule along a polyline, as the volume of the ball
+    // swept along each segment, corrected around the bends. In the plane
+    // of a bend of angle θ, a wedge of angle θ of the ball around the
+    // vertex is left on the outer side, while the cylinders of the two
+    // segments overlap on the inner side, over a kite of half-width w and
+    // area w² tan(θ/2), where w is the half-width of the section.
+    //
+    // This is exact when the segments are long enough for the corrections
+    // at both of their ends not to overlap, and the segments which are not
+    // adjacent are far apart. Otherwise, `None` is returned.
+    fn polyline_volume(vertices: &[Position], radius: f64) -> Option<f64> {
+        let points = vertices.iter().map(Vec::<f64>::from).collect::<Vec<_>>();
+        let n = points.first().map_or(0, |point| point.len());
+        if n < 2 || points.iter().any(|point| point.len() != n) {
+            return None;
+        }
+
+        // Length and direction of each segment.
+        let mut segments = vec![];
+        for segment in points.windows(2) {
+            let d = segment[1]
+                .iter()
+                .zip(&segment[0])
+                .map(|(b, a)| b - a)
+                .collect::<Vec<_>>();
+            let length = d.iter().map(|d| d * d).sum::<f64>().sqrt();
+            if length == 0.0 {
+                return None;
+            }
+            segments.push((length, d.iter().map(|d| d / length).collect::<Vec<_>>()));
+        }
+
+        let angles = segments
+            .windows(2)
+            .map(|s| {
+                let cos = s[0].1.iter().zip(&s[1].1).map(|(u, v)| u * v).sum::<f64>();
+                cos.clamp(-1.0, 1.0).acos()
+            })
+            .collect::<Vec<_>>();
+
+        for (i, (length, _)) in segments.iter().enumerate() {
+            let before = if i > 0 {
+                (angles[i - 1] / 2.0).tan()
+            } else {
+                0.0
+            };
+            let after = angles.get(i).map_or(0.0, |angle| (angle / 2.0).tan());
+            if *length < radius * (before + after).max(1.0) {
+                return None;
+            }
+        }
+
+        let capsules = vertices
+            .windows(2)
+            .map(|segment| Shape::Capsule(segment.to_vec(), radius.into()))
+            .collect::<Vec<_>>();
+        for (i, a) in capsules.iter().enumerate() {
+            for b in capsules.iter().skip(i + 2) {
+                if !Shape::Intersection(vec![a.clone(), b.clone()]).is_mbb_empty() {
+                    return None;
+                }
+            }
+        }
+
+        let ball = Shape::ball_volume(n, radius);
+        let section = Shape::ball_volume(n - 1, radius);
+        // Integral of w² over the directions orthogonal to the plane of
+        // the bend.
+        let kite = Shape::ball_volume(n - 2, radius) * radius * radius * 2.0 / n as f64;
+
+        let length = segments.iter().map(|(length, _)| length).sum::<f64>();
+        let bends = angles
+            .iter()
+            .map(|angle| angle / (2.0 * std::f64::consts::PI) * ball - (angle / 2.0).tan() * kite)
+            .sum::<f64>();
+
+        Some(ball + section * length + bends)
+    }
+
+    // Bounding boxes of the shapes which are not composite, within the
+    // shape.
+    fn leaves_mbb(&self, boxes: &mut Vec<(Position, Position)>) {
+        match self {
+            Shape::Union(shapes) | Shape::Intersection(shapes) => {
+                shapes.iter().for_each(|shape| shape.leaves_mbb(boxes))
+            }
+            Shape::Difference(shape, removed) => {
+                shape.leaves_mbb(boxes);
+                removed.leaves_mbb(boxes);
+            }
+            Shape::Capsule(vertices, radius) if vertices.len() > 2 => {
+                for segment in vertices.windows(2) {
+                    boxes.push(Shape::Capsule(segment.to_vec(), *radius).get_mbb());
+                }
+            }
+            _ => boxes.push(self.get_mbb()),
+        }
+    }
+
+    // Determinant of the matrix whose rows are `vectors`, through Gaussian
+    // elimination, or zero if it is not square.
+    fn determinant(vectors: &[Position]) -> f64 {
+        let n = vectors.len();
+        if vectors.iter().any(|vector| vector.dimensions() != n) {
+            return 0.0;
+        }
+
+        let mut m = vectors.iter().map(Vec::<f64>::from).collect::<Vec<_>>();
+        let mut determinant = 1.0;
+        for c in 0..n {
+            let pivot = (c..n)
+                .max_by(|&a, &b| m[a][c].abs().total_cmp(&m[b][c].abs()))
+                .unwrap_or(c);
+            if m[pivot][c] == 0.0 {
+                return 0.0;
+            }
+            if pivot != c {
+                m.swap(c, pivot);
+                determinant = -determinant;
+            }
+            determinant *= m[c][c];
+
+            let pivot = m[c].clone();
+            for row in m.iter_mut().skip(c + 1) {
+                let factor = row[c] / pivot[c];
+                for (value, p) in row.iter_mut().zip(&pivot).skip(c) {
+                    *value -= factor * p;
+                }
+            }
+        }
+
+        determinant
+    }
+
+    // Estimate the volume of the shape by sampling its minimum bounding
+    // box, for shapes without a closed formula.
+    //
+    // The bounding box is divided into a regular grid, further split
+    // along the faces of the bounding boxes of the shapes it is made of,
+    // and each cell of the grid is tested at its center. This is exact
+    // for shapes made of bounding boxes only.
+    fn sample_volume(&self) -> f64 {
+        // Approximate number of cells of the regular grid.
+        const SAMPLES: f64 = 32768.0;
+
+        if self.is_mbb_empty() {
+            return 0.0;
+        }
+
+        let (lower, higher) = self.get_mbb();
+        let (lower, higher): (Vec<f64>, Vec<f64>) = (lower.into(), higher.into());
+        let dimensions = lower.len();
+        let steps = SAMPLES.powf(1.0 / dimensions as f64).ceil().max(1.0) as usize;
+
+        let mut leaves = vec![];
+        self.leaves_mbb(&mut leaves);
+
+        // Boundaries of the cells, along each axis.
+        let boundaries = (0..dimensions)
+            .map(|k| {
+                let (l, h) = (lower[k], higher[k]);
+                let mut boundaries = (0..=steps)
+                    .map(|i| l + (h - l) * i as f64 / steps as f64)
+                    .chain(
+                        leaves
+                            .iter()
+                            .flat_map(|(a, b)| vec![a[k].f64(), b[k].f64()])
+                            .filter(|&v| l < v && v < h),
+                    )
+                    .collect::<Vec<_>>();
+                boundaries.sort_unstable_by(|a, b| a.total_cmp(b));
+                boundaries.dedup();
+                boundaries
+            })
+            .collect::<Vec<_>>();
+
+        if boundaries.iter().any(|boundaries| boundaries.len() < 2) {
+            return 0.0;
+        }
+
+        let mut indices = vec![0; dimensions];
+        let mut volume = 0.0;
+        loop {
+            let position = (0..dimensions)
+                .map(|k| (boundaries[k][indices[k]] + boundaries[k][indices[k] + 1]) / 2.0)
+                .collect::<Position>();
+
+            if self.contains(&position) {
+                volume += (0..dimensions)
+                    .map(|k| boundaries[k][indices[k] + 1] - boundaries[k][indices[k]])
+                    .product::<f64>();
+            }
+
+            match (0..dimensions).find(|&k| indices[k] + 2 < boundaries[k].len()) {
+                None => break,
+                Some(k) => {
+                    indices[k] += 1;
+                    indices[..k].iter_mut().for_each(|index| *index = 0);
+                }
+            }
+        }
+
+        volume
+    }
+}
+
+/* Original version proposed by Charles François Rey - 2019
+```perl
+use strict;
+
+my $conf = [[0, 2], [1, 3], [11, 20], [5, 6]];
+my $dim = scalar @{$conf};
+
+sub nxt {
+    my ($state) = @_;
+    foreach my $i (0..$dim-1) {
+        $i = $dim-1-$i;
+        $state->[$i] = $state->[$i] + 1;
+        if ($state->[$i] > $conf->[$i]->[-1]) {
+            $state->[$i] = $conf->[$i]->[0];
+            # => carry
+        } else {
+            return 1;
+        }
+    }
+    return;
+}
+
+sub pretty {
+    my ($state) = @_;
+    return "(", join(', ', @{$state}), ")";
+}
+
+sub first {
+    return [ map { $_->[0] } @{$conf} ];
+}
+
+my $i = 0;
+my $s = first;
+do {
+    print $i++, ": ", pretty($s), "\n";
+} while (nxt($s))
+```*/
+// Positions of a regular grid, from `lower` included, to `higher`
+// excluded, along each dimension. The first position, `lower`, is
+// always generated.
+#[derive(Clone, Debug)]
+struct Grid {
+    lower: Position,
+    higher: Position,
+    next: Option<Position>,
+}
+
+impl Grid {
+    fn new(lower: &Position, higher: &Position) -> Self {
+        // Redefine lower as a compacted form of lower for all coordinates.
+        let lower: Position = (0..lower.dimensions())
+            .map(|i| lower[i].u64())
+            .collect::<Vec<_>>()
+            .into();
+
+        Grid {
+            next: Some(lower.clone()),
+            lower,
+            higher: higher.clone(),
+        }
+    }
+}
+
+impl Iterator for Grid {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+
+        let mut state = current.clone();
+        for i in (0..state.dimensions()).rev() {
+            state[i] = (state[i].u64() + 1).into();
+            if state[i] >= self.higher[i] {
+                state[i] = self.lower[i];
+            // => carry
+            } else {
+                self.next = Some(state);
+                break;
+            }
+        }
+
+        Some(current)
+    }
+}
+
+/// Lazy iterator over the positions which approximate a shape.
+///
+/// See [Shape::rasterise_iter](enum.Shape.html#method.rasterise_iter).
+#[derive(Clone, Debug)]
+pub struct Cells<'s> {
+    shape: &'s Shape,
+    point: Option<Position>,
+    // Grids left to scan, in reverse order, with the offset of the
+    // segment they cover, for capsules.
+    grids: Vec<(Grid, usize)>,
+}
+
+impl Cells<'_> {
+    fn keep(&self, position: &Position, segment: usize) -> bool {
+        match self.shape {
+            Shape::BoundingBox(_, _) => true,
+            Shape::Capsule(vertices, radius) if vertices.len() > 2 => {
+                // Keep positions in the first segment containing them.
+                let within = |s: &[Position]| {
+                    Shape::segment_distance(&s[0], &s[1], position) <= radius.f64()
+                };
+                let mut segments = vertices.windows(2);
+
+                segments.by_ref().take(segment).all(|s| !within(s))
+                    && matches!(segments.next(), Some(s) if within(s))
+            }
+            shape => shape.contains(position),
+        }
+    }
+}
+
+impl Iterator for Cells<'_> {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(point) = self.point.take() {
+            return Some(point);
+        }
+
+        loop {
+            let (grid, segment) = self.grids.last_mut()?;
+            let segment = *segment;
+
+            match grid.next() {
+                None => {
+                    self.grids.pop();
+                }
+                Some(position) => {
+                    if self.keep(&position, segment) {
+                        return Some(position);
+                    }
+                }
+            }
         }
     }
 }
@@ -288,3 +288,364 @@ pub fn check_r3() {
 
     assert_eq!(format!("{:?}", t1), "[Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(3), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(4), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(5), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(6), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(7), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(8), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(9), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(10), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(11), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(12), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(13), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(14), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(15), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(16), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(17), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(18), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(19), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(20), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(21), CoordinateU8(22)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(5)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(6)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(7)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(8)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(9)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(10)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(11)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(12)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(13)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(14)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(15)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(16)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(17)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(18)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(19)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(20)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(21)]), Position3([CoordinateU8(17), CoordinateU8(22), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(3), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(4), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(5), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(6), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(7), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(8), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(9), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(10), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(11), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(12), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(13), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(14), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(15), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(16), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(17), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(18), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(19), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(20), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(21), CoordinateU8(22)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(5)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(6)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(7)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(8)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(9)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(10)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(11)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(12)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(13)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(14)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(15)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(16)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(17)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(18)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(19)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(20)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(21)]), Position3([CoordinateU8(18), CoordinateU8(22), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(3), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(4), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(5), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(6), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(7), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(8), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(9), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(10), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(11), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(12), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(13), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(14), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(15), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(16), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(17), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(18), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(19), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(20), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(21), CoordinateU8(22)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(5)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(6)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(7)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(8)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(9)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(10)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(11)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(12)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(13)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(14)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(15)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(16)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(17)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(18)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(19)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(20)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(21)]), Position3([CoordinateU8(19), CoordinateU8(22), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(3), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(4), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(5), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(6), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(7), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(8), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(9), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(10), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(11), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(12), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(13), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(14), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(15), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(16), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(17), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(18), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(19), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(20), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(21), CoordinateU8(22)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(5)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(6)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(7)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(8)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(9)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(10)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(11)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(12)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(13)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(14)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(15)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(16)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(17)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(18)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(19)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(20)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(21)]), Position3([CoordinateU8(20), CoordinateU8(22), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(3), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(4), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(5), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(6), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(7), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(8), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(9), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(10), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(11), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(12), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(13), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(14), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(15), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(16), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(17), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(18), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(19), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(20), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(21), CoordinateU8(22)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(5)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(6)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(7)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(8)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(9)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(10)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(11)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(12)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(13)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(14)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(15)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(16)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(17)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(18)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(19)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(20)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(21)]), Position3([CoordinateU8(21), CoordinateU8(22), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(3), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(4), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(5), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(6), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(7), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(8), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(9), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(10), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(11), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(12), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(13), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(14), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(15), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(16), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(17), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(18), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(19), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(20), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(21), CoordinateU8(22)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(5)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(6)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(7)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(8)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(9)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(10)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(11)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(12)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(13)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(14)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(15)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(16)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(17)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(18)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(19)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(20)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(21)]), Position3([CoordinateU8(22), CoordinateU8(22), CoordinateU8(22)])]");
 }
+
+// Cube of side 4, with a corner on the origin, and its faces oriented
+// outwards.
+fn get_cube() -> (Vec<Position>, Vec<[usize; 3]>) {
+    let vertices = (0..8)
+        .map(|i| {
+            (0..3)
+                .map(|k| if i & (1 << k) == 0 { 0.0 } else { 4.0 })
+                .collect::<Vec<_>>()
+                .into()
+        })
+        .collect();
+
+    let faces = vec![
+        [0, 2, 3],
+        [0, 3, 1],
+        [4, 5, 7],
+        [4, 7, 6],
+        [0, 1, 5],
+        [0, 5, 4],
+        [2, 6, 7],
+        [2, 7, 3],
+        [0, 4, 6],
+        [0, 6, 2],
+        [1, 3, 7],
+        [1, 7, 5],
+    ];
+
+    (vertices, faces)
+}
+
+#[test]
+pub fn mesh_winding() {
+    let (vertices, faces) = get_cube();
+    let inverted = faces.iter().map(|&[a, b, c]| [a, c, b]).collect();
+
+    for mesh in vec![
+        Shape::Mesh(vertices.clone(), faces),
+        Shape::Mesh(vertices, inverted),
+    ] {
+        assert!(mesh.contains(&vec![2.0, 2.0, 2.0].into()));
+        assert!(mesh.contains(&vec![0.5, 3.5, 1.0].into()));
+        // Positions on the faces, edges and corners are inside.
+        assert!(mesh.contains(&vec![4.0, 2.0, 2.0].into()));
+        assert!(mesh.contains(&vec![0.0, 0.0, 2.0].into()));
+        assert!(mesh.contains(&vec![4.0, 4.0, 4.0].into()));
+        assert!(!mesh.contains(&vec![4.5, 2.0, 2.0].into()));
+        assert!(!mesh.contains(&vec![-1.0, -1.0, -1.0].into()));
+
+        assert!((mesh.volume() - 64.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+pub fn mesh_rasterise() {
+    let space = get1();
+    let (vertices, faces) = get_cube();
+    let mesh = Shape::Mesh(vertices, faces);
+
+    // Cells are inside when their lower corner is, so the faces of the
+    // cube are included, as for bounding boxes whose higher corner is
+    // included.
+    let mut cells = mesh.encode(&space).unwrap().rasterise().unwrap();
+    let lower = space.encode(&[0.0, 0.0, 0.0]).unwrap();
+    let higher = space.encode(&[5.0, 5.0, 5.0]).unwrap();
+    let mut expected = Shape::BoundingBox(lower, higher).rasterise().unwrap();
+
+    // Use the total order, as positions are only partially ordered.
+    cells.sort_unstable_by(Ord::cmp);
+    expected.sort_unstable_by(Ord::cmp);
+    assert_eq!(cells.len(), 125);
+    assert_eq!(cells, expected);
+}
+
+#[test]
+pub fn mesh_validation() {
+    let space = get1();
+    let (vertices, faces) = get_cube();
+
+    let invalid = vec![
+        Shape::Mesh(vec![], vec![]),
+        Shape::Mesh(vertices.clone(), vec![]),
+        Shape::Mesh(vertices.clone(), vec![[0, 1, 8]]),
+    ];
+    for mesh in &invalid {
+        assert!(matches!(
+            mesh.encode(&space),
+            Err(Error::InvalidDefinition(_))
+        ));
+        assert!(matches!(
+            mesh.rebase(&space, &get2()),
+            Err(Error::InvalidDefinition(_))
+        ));
+    }
+
+    // The bounding box of an empty mesh is empty.
+    assert!(invalid[0].is_mbb_empty());
+
+    // Meshes are three-dimensional.
+    let plane = Space::new(
+        "plane",
+        CoordinateSystem::new(
+            vec![0.0, 0.0],
+            vec![
+                Axis::new("cm", vec![1.0, 0.0], NumberSet::R, 0.0, 10.0, 10).unwrap(),
+                Axis::new("cm", vec![0.0, 1.0], NumberSet::R, 0.0, 10.0, 10).unwrap(),
+            ],
+        ),
+    );
+    let mesh = Shape::Mesh(vec![vec![1.0, 1.0].into(); 3], vec![[0, 1, 2]]);
+    assert!(matches!(
+        mesh.encode(&plane),
+        Err(Error::InvalidDefinition(_))
+    ));
+
+    let mesh = Shape::Mesh(vertices, faces);
+    assert_eq!(
+        format!("{:?}", mesh.get_mbb()),
+        "(Position3([CoordinateF64(0.0), CoordinateF64(0.0), CoordinateF64(0.0)]), Position3([CoordinateF64(4.0), CoordinateF64(4.0), CoordinateF64(4.0)]))"
+    );
+    assert!(mesh.rebase(&space, &get2()).is_ok());
+}
+
+// Grid of 5x3x2 voxels, spaced by 10, 5 and 2 along the axes, with its
+// last voxels along the first axis on the border of `get1`, and every
+// other voxel selected.
+fn get_mask() -> VoxelMask {
+    let affine = [
+        [10.0, 0.0, 0.0, -5.0],
+        [0.0, 5.0, 0.0, 1.0],
+        [0.0, 0.0, 2.0, 3.0],
+    ];
+    let voxels = (0..30).map(|v| v % 2 == 0).collect();
+
+    VoxelMask::new([5, 3, 2], affine, voxels).unwrap()
+}
+
+#[test]
+pub fn voxel_mask_inverse() {
+    let mask = get_mask();
+
+    for (v, &selected) in mask.voxels().iter().enumerate() {
+        let (i, j, k) = ((v % 5) as f64, ((v / 5) % 3) as f64, (v / 15) as f64);
+        let center = vec![10.0 * i - 5.0, 5.0 * j + 1.0, 2.0 * k + 3.0];
+        assert_eq!(mask.contains(&center.clone().into()), selected);
+
+        // Positions within half a step of a center belong to its voxel.
+        let near = vec![center[0] + 4.9, center[1] - 2.4, center[2] + 0.9];
+        assert_eq!(mask.contains(&near.into()), selected);
+    }
+
+    assert!(!mask.contains(&vec![-10.5, 1.0, 3.0].into()));
+    assert!(!mask.contains(&vec![45.5, 1.0, 3.0].into()));
+    assert!(!mask.contains(&vec![0.0, 0.0].into()));
+
+    assert_eq!(format!("{:?}", mask.centers().len()), "15");
+    assert!((mask.volume() - 15.0 * 100.0).abs() < 1e-9);
+
+    // Singular transformations cannot be inverted.
+    let flat = [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0; 4]];
+    assert!(matches!(
+        VoxelMask::new([1, 1, 1], flat, vec![true]),
+        Err(Error::InvalidDefinition(_))
+    ));
+}
+
+#[test]
+pub fn voxel_mask_transform() {
+    let space = get1();
+    let mask = get_mask();
+
+    // The grid reaches the end of the first axis, which a transformation
+    // sampled past the last voxel would not be able to encode.
+    let encoded = Shape::VoxelMask(mask.clone()).encode(&space).unwrap();
+    let decoded = encoded.decode(&space).unwrap();
+
+    match (&encoded, &decoded) {
+        (Shape::VoxelMask(encoded), Shape::VoxelMask(decoded)) => {
+            assert_eq!(encoded.dimensions(), mask.dimensions());
+            assert_eq!(encoded.voxels(), mask.voxels());
+            for (r, row) in decoded.affine().iter().enumerate() {
+                for (c, value) in row.iter().enumerate() {
+                    assert!((value - mask.affine()[r][c]).abs() < 1e-9);
+                }
+            }
+        }
+        shapes => panic!("unexpected shapes {:?}", shapes),
+    }
+
+    for center in mask.centers() {
+        let center: Vec<f64> = (&center).into();
+        assert!(encoded.contains(&space.encode(&center).unwrap()));
+    }
+}
+
+fn get_box(lower: f64, higher: f64) -> Shape {
+    Shape::BoundingBox(vec![lower; 3].into(), vec![higher; 3].into())
+}
+
+#[test]
+pub fn csg_contains() {
+    let (a, b) = (get_box(0.0, 4.0), get_box(2.0, 6.0));
+    let inside_a: Position = vec![1.0, 1.0, 1.0].into();
+    let inside_both: Position = vec![3.0, 3.0, 3.0].into();
+    let inside_b: Position = vec![5.0, 5.0, 5.0].into();
+    let outside: Position = vec![7.0, 7.0, 7.0].into();
+
+    let union = Shape::Union(vec![a.clone(), b.clone()]);
+    let intersection = Shape::Intersection(vec![a.clone(), b.clone()]);
+    let difference = Shape::Difference(Box::new(a), Box::new(b));
+
+    let contains = |shape: &Shape| {
+        [&inside_a, &inside_both, &inside_b, &outside]
+            .iter()
+            .map(|position| shape.contains(position))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(contains(&union), vec![true, true, true, false]);
+    assert_eq!(contains(&intersection), vec![false, true, false, false]);
+    assert_eq!(contains(&difference), vec![true, false, false, false]);
+
+    // An intersection of no shapes is empty.
+    let empty = Shape::Intersection(vec![]);
+    assert_eq!(contains(&empty), vec![false, false, false, false]);
+    assert!(empty.is_mbb_empty());
+
+    // Shapes can be nested.
+    let nested = Shape::Union(vec![difference, get_box(5.0, 8.0)]);
+    assert_eq!(contains(&nested), vec![true, false, true, true]);
+}
+
+#[test]
+pub fn csg_volume() {
+    let (a, b, c) = (get_box(0.0, 4.0), get_box(2.0, 6.0), get_box(10.0, 12.0));
+    let volume = |shape: Shape| shape.volume();
+
+    // Shapes made of bounding boxes have an exact volume.
+    assert_eq!(volume(Shape::Union(vec![a.clone(), c.clone()])), 72.0);
+    assert_eq!(volume(Shape::Union(vec![a.clone(), b.clone()])), 120.0);
+    assert_eq!(volume(Shape::Intersection(vec![a.clone(), b.clone()])), 8.0);
+    assert_eq!(volume(Shape::Intersection(vec![a.clone(), c.clone()])), 0.0);
+    assert_eq!(volume(Shape::Intersection(vec![])), 0.0);
+    assert_eq!(
+        volume(Shape::Difference(Box::new(a.clone()), Box::new(b))),
+        56.0
+    );
+    assert_eq!(
+        volume(Shape::Difference(Box::new(a.clone()), Box::new(c))),
+        64.0
+    );
+
+    // Other shapes are sampled: an eighth of a sphere of radius 2.
+    let sphere = Shape::HyperSphere(vec![0.0; 3].into(), 2.0.into());
+    let expected = 4.0 / 3.0 * std::f64::consts::PI * 8.0 / 8.0;
+    let sampled = volume(Shape::Intersection(vec![sphere, get_box(0.0, 2.0)]));
+    assert!((sampled - expected).abs() < 0.02 * expected);
+}
+
+fn get_capsule(vertices: &[[f64; 3]], radius: f64) -> Shape {
+    Shape::Capsule(
+        vertices.iter().map(|v| v.to_vec().into()).collect(),
+        radius.into(),
+    )
+}
+
+#[test]
+pub fn capsule_volume() {
+    let pi = std::f64::consts::PI;
+
+    // Straight capsules: a cylinder capped by two half balls.
+    let straight = get_capsule(&[[0.0; 3], [10.0, 0.0, 0.0]], 1.0);
+    assert!((straight.volume() - (4.0 / 3.0 * pi + 10.0 * pi)).abs() < 1e-9);
+    let collinear = get_capsule(&[[0.0; 3], [4.0, 0.0, 0.0], [10.0, 0.0, 0.0]], 1.0);
+    assert!((collinear.volume() - straight.volume()).abs() < 1e-9);
+
+    // Two right angle bends, each adding a quarter of the ball on the
+    // outer side, and removing the overlap of the cylinders on the inner
+    // side.
+    let vertices = [
+        [0.0; 3],
+        [10.0, 0.0, 0.0],
+        [10.0, 10.0, 0.0],
+        [10.0, 10.0, 10.0],
+    ];
+    let bent = get_capsule(&vertices, 1.0);
+    let expected = 4.0 / 3.0 * pi + 30.0 * pi + 2.0 * (pi / 3.0 - 4.0 / 3.0);
+    assert!((bent.volume() - expected).abs() < 1e-9);
+
+    // In two dimensions as well.
+    let flat = Shape::Capsule(
+        vec![
+            vec![0.0, 0.0].into(),
+            vec![10.0, 0.0].into(),
+            vec![10.0, 10.0].into(),
+        ],
+        1.0.into(),
+    );
+    let expected = pi + 2.0 * 20.0 + pi / 4.0 - 1.0;
+    assert!((flat.volume() - expected).abs() < 1e-9);
+
+    // Sharp bends have no closed formula, and are sampled.
+    let hairpin = get_capsule(&[[0.0; 3], [10.0, 0.0, 0.0], [0.0, 1.0, 0.0]], 1.0);
+    let volume = hairpin.volume();
+    assert!(volume > straight.volume() && volume < 2.0 * straight.volume());
+}
+
+#[test]
+pub fn capsule_rebase() {
+    // Steps of 1 along the first axis, and 0.5 along the others.
+    let axis = |unit_vector: Vec<f64>, steps: u64| {
+        Axis::new("cm", unit_vector, NumberSet::R, 0.0, 10.0, steps).unwrap()
+    };
+    let space = Space::new(
+        "anisotropic",
+        CoordinateSystem::new(
+            vec![0.0; 3],
+            vec![
+                axis(vec![1.0, 0.0, 0.0], 10),
+                axis(vec![0.0, 1.0, 0.0], 20),
+                axis(vec![0.0, 0.0, 1.0], 20),
+            ],
+        ),
+    );
+
+    // The section stays spherical, with the longest semi-axis as radius,
+    // so the encoded capsule covers at least the original one.
+    let capsule = get_capsule(&[[2.0, 2.0, 2.0], [8.0, 2.0, 2.0]], 1.0);
+    match capsule.encode(&space).unwrap() {
+        Shape::Capsule(vertices, radius) => {
+            assert_eq!(vertices.len(), 2);
+            assert!((radius.f64() - 2.0).abs() < 1e-9);
+        }
+        shape => panic!("unexpected shape {:?}", shape),
+    }
+
+    // The Universe has no axes, and its coordinates are not encoded.
+    let universe = Space::universe();
+    let encoded = capsule.encode(universe).unwrap();
+    assert_eq!(format!("{:?}", encoded), format!("{:?}", capsule));
+    assert_eq!(
+        format!("{:?}", encoded.decode(universe).unwrap()),
+        format!("{:?}", capsule)
+    );
+
+    let rebased = capsule
+        .encode(&space)
+        .unwrap()
+        .rebase(&space, universe)
+        .unwrap();
+    match rebased.rebase(universe, &space).unwrap() {
+        Shape::Capsule(vertices, radius) => {
+            assert_eq!(vertices.len(), 2);
+            assert!((radius.f64() - 4.0).abs() < 1e-9);
+        }
+        shape => panic!("unexpected shape {:?}", shape),
+    }
+    match rebased {
+        Shape::Capsule(_, radius) => assert!((radius.f64() - 0.02).abs() < 1e-9),
+        shape => panic!("unexpected shape {:?}", shape),
+    }
+}
@@ -0,0 +1,231 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Position;
+use crate::Error;
+
+// Determinant of the linear part of an affine transformation.
+fn determinant(affine: &[[f64; 4]; 3]) -> f64 {
+    let m = |r: usize, c: usize| affine[r][c];
+
+    m(0, 0) * (m(1, 1) * m(2, 2) - m(1, 2) * m(2, 1))
+        - m(0, 1) * (m(1, 0) * m(2, 2) - m(1, 2) * m(2, 0))
+        + m(0, 2) * (m(1, 0) * m(2, 1) - m(1, 1) * m(2, 0))
+}
+
+/// Dense three-dimensional grid of voxels, selecting a volume.
+///
+/// The grid is placed in space by an affine transformation, which maps
+/// the indices of a voxel to the position of its center, as in NIfTI-1.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct VoxelMask {
+    dimensions: [usize; 3],
+    // Rows of the transformation of (i, j, k, 1) into a position.
+    affine: [[f64; 4]; 3],
+    // Rows of the inverse transformation.
+    inverse: [[f64; 4]; 3],
+    // Selection flag of each voxel, with `i` varying the fastest.
+    voxels: Vec<bool>,
+}
+
+impl VoxelMask {
+    /// Instantiate a new voxel mask.
+    ///
+    /// # Parameters
+    ///
+    ///  * `dimensions`:
+    ///      Number of voxels along each of the three indices.
+    ///
+    ///  * `affine`:
+    ///      Rows of the affine transformation of the indices of a voxel,
+    ///      as `(i, j, k, 1)`, into the position of its center.
+    ///
+    ///  * `voxels`:
+    ///      Selection flag of each voxel, with the first index varying
+    ///      the fastest, then the second one.
+    pub fn new(
+        dimensions: [usize; 3],
+        affine: [[f64; 4]; 3],
+        voxels: Vec<bool>,
+    ) -> Result<Self, Error> {
+        let count = dimensions.iter().product::<usize>();
+        if voxels.len() != count {
+            return Err(Error::InvalidDefinition(format!(
+                "Voxel mask of {:?} voxels with {} values",
+                dimensions,
+                voxels.len()
+            )));
+        }
+
+        let m = |r: usize, c: usize| affine[r][c];
+        let det = determinant(&affine);
+
+        if det == 0.0 || !det.is_finite() {
+            return Err(Error::InvalidDefinition(format!(
+                "Voxel mask affine transformation is not invertible: {:?}",
+                affine
+            )));
+        }
+
+        // Inverse of the linear part, through the adjugate matrix.
+        let mut inverse = [[0.0; 4]; 3];
+        for (r, row) in inverse.iter_mut().enumerate() {
+            for (c, value) in row.iter_mut().take(3).enumerate() {
+                let (r1, r2) = ((c + 1) % 3, (c + 2) % 3);
+                let (c1, c2) = ((r + 1) % 3, (r + 2) % 3);
+                *value = (m(r1, c1) * m(r2, c2) - m(r1, c2) * m(r2, c1)) / det;
+            }
+            row[3] = -(0..3).map(|c| row[c] * m(c, 3)).sum::<f64>();
+        }
+
+        Ok(VoxelMask {
+            dimensions,
+            affine,
+            inverse,
+            voxels,
+        })
+    }
+
+    /// Number of voxels along each of the three indices.
+    pub fn dimensions(&self) -> &[usize; 3] {
+        &self.dimensions
+    }
+
+    /// Rows of the affine transformation of the indices of a voxel into
+    /// the position of its center.
+    pub fn affine(&self) -> &[[f64; 4]; 3] {
+        &self.affine
+    }
+
+    /// Selection flag of each voxel.
+    pub fn voxels(&self) -> &[bool] {
+        &self.voxels
+    }
+
+    // Position of the indices `(i, j, k)`, which can be fractional.
+    fn position(&self, indices: [f64; 3]) -> Position {
+        self.affine
+            .iter()
+            .map(|row| row[0] * indices[0] + row[1] * indices[1] + row[2] * indices[2] + row[3])
+            .collect()
+    }
+
+    /// Positions of the centers of the selected voxels.
+    pub fn centers(&self) -> Vec<Position> {
+        let [ni, nj, _] = self.dimensions;
+
+        self.voxels
+            .iter()
+            .enumerate()
+            .filter(|(_, &selected)| selected)
+            .map(|(v, _)| {
+                let indices = [v % ni, (v / ni) % nj, v / (ni * nj)];
+                self.position([indices[0] as f64, indices[1] as f64, indices[2] as f64])
+            })
+            .collect()
+    }
+
+    /// Apply an affine transformation to the placement of the grid.
+    ///
+    /// The transformation is sampled at the centers of the first and last
+    /// voxels along each index, to limit the impact of any rounding it
+    /// does, while staying within the grid. Indices with a single voxel
+    /// are sampled one step away.
+    ///
+    /// # Parameters
+    ///
+    ///  * `f`:
+    ///      The affine transformation of a position.
+    pub fn transform<F>(&self, f: F) -> Result<Self, Error>
+    where
+        F: Fn(&Position) -> Result<Position, Error>,
+    {
+        let origin = f(&self.position([0.0; 3]))?;
+        if origin.dimensions() != 3 {
+            return Err(Error::DimensionMismatch(3, origin.dimensions()));
+        }
+
+        let mut affine = [[0.0; 4]; 3];
+        for (r, row) in affine.iter_mut().enumerate() {
+            row[3] = origin[r].f64();
+        }
+
+        for c in 0..3 {
+            let steps = self.dimensions[c].saturating_sub(1).max(1) as f64;
+            let mut indices = [0.0; 3];
+            indices[c] = steps;
+
+            let corner = f(&self.position(indices))?;
+            if corner.dimensions() != 3 {
+                return Err(Error::DimensionMismatch(3, corner.dimensions()));
+            }
+
+            for (r, row) in affine.iter_mut().enumerate() {
+                row[c] = (corner[r].f64() - origin[r].f64()) / steps;
+            }
+        }
+
+        VoxelMask::new(self.dimensions, affine, self.voxels.clone())
+    }
+
+    /// Compute the minimum bounding box of the voxels of the grid.
+    pub fn get_mbb(&self) -> (Position, Position) {
+        let mut lower = vec![f64::INFINITY; 3];
+        let mut higher = vec![f64::NEG_INFINITY; 3];
+
+        // Voxels extend half a step around their center.
+        for corner in 0..8 {
+            let mut indices = [0.0; 3];
+            for (k, index) in indices.iter_mut().enumerate() {
+                *index = if corner & (1 << k) == 0 {
+                    -0.5
+                } else {
+                    self.dimensions[k] as f64 - 0.5
+                };
+            }
+
+            let position = self.position(indices);
+            for k in 0..3 {
+                lower[k] = lower[k].min(position[k].f64());
+                higher[k] = higher[k].max(position[k].f64());
+            }
+        }
+
+        (lower.into(), higher.into())
+    }
+
+    /// Check if the position is within a selected voxel.
+    ///
+    /// # Parameters
+    ///
+    ///  * `position`:
+    ///      The position to check.
+    pub fn contains(&self, position: &Position) -> bool {
+        if position.dimensions() != 3 {
+            return false;
+        }
+
+        let mut voxel = 0;
+        let mut stride = 1;
+        for (k, row) in self.inverse.iter().enumerate() {
+            let index = (0..3).map(|c| row[c] * position[c].f64()).sum::<f64>() + row[3];
+            let index = index.round();
+
+            if index < 0.0 || index >= self.dimensions[k] as f64 {
+                return false;
+            }
+
+            voxel += index as usize * stride;
+            stride *= self.dimensions[k];
+        }
+
+        self.voxels[voxel]
+    }
+
+    /// Compute the volume of the selected voxels.
+    pub fn volume(&self) -> f64 {
+        let det = determinant(&self.affine);
+
+        self.voxels.iter().filter(|&&selected| selected).count() as f64 * det.abs()
+    }
+}
@@ -1,8 +1,11 @@
 use std::cmp::Ordering;
 use std::collections::hash_map::DefaultHasher;
-use std::collections::HashMap;
+use std::collections::HashSet;
 use std::hash::Hash;
 use std::hash::Hasher;
+use std::sync::Arc;
+use std::thread;
+use std::thread::ScopedJoinHandle;
 
 use serde::Deserialize;
 use serde::Serialize;
@@ -15,6 +18,7 @@ use super::space_index::SpaceIndex;
 use super::space_index::SpaceSetIndex;
 use super::space_index::SpaceSetObject;
 use super::CoreQueryParameters;
+use super::Error;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SpaceDB {
@@ -22,142 +26,233 @@ pub struct SpaceDB {
     resolutions: Vec<SpaceIndex>,
 }
 
+// Remove `shift` bits of precision from the positions of `objects`, and
+// drop the duplicates, keeping the first occurrence of each object.
+//
+// The work is split over `threads` threads, but the result does not depend
+// on their number.
+fn reduce_precision(objects: &[SpaceSetObject], shift: u32, threads: usize) -> Vec<SpaceSetObject> {
+    let chunk_size = (objects.len() / threads).max(1);
+
+    let reduced = thread::scope(|scope| {
+        objects
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|o| {
+                            let mut o = o.clone();
+                            let mut hasher = DefaultHasher::new();
+                            o.set_position(o.position().reduce_precision(shift));
+
+                            // Hash, AFTER updating the position.
+                            o.hash(&mut hasher);
+
+                            (hasher.finish(), o)
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    // Each thread looks for the first occurrences of a subset of the hashes.
+    let mut keep = reduced
+        .iter()
+        .map(|chunk| vec![false; chunk.len()])
+        .collect::<Vec<_>>();
+
+    let first = thread::scope(|scope| {
+        let reduced = &reduced;
+
+        (0..threads)
+            .map(|subset| {
+                scope.spawn(move || {
+                    let mut seen = HashSet::new();
+                    let mut first = vec![];
+
+                    for (c, chunk) in reduced.iter().enumerate() {
+                        for (i, (hash, _)) in chunk.iter().enumerate() {
+                            if *hash % threads as u64 == subset as u64 && seen.insert(*hash) {
+                                first.push((c, i));
+                            }
+                        }
+                    }
+
+                    first
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    for (c, i) in first.into_iter().flatten() {
+        keep[c][i] = true;
+    }
+
+    reduced
+        .into_iter()
+        .zip(keep)
+        .flat_map(|(chunk, keep)| {
+            chunk
+                .into_iter()
+                .zip(keep)
+                .filter_map(|((_, o), keep)| if keep { Some(o) } else { None })
+        })
+        .collect()
+}
+
+// Index of a resolution, possibly still being built.
+enum Pending<'scope> {
+    Built(SpaceSetIndex),
+    Building(ScopedJoinHandle<'scope, SpaceSetIndex>),
+}
+
 impl SpaceDB {
+    // Build the resolutions of the index of a reference space.
+    //
+    // `threads` is the number of threads used to reduce the precision of
+    // the positions. When more than one, the index of each resolution is
+    // also built by its own thread.
     pub fn new(
         reference_space: &Space,
-        mut space_objects: Vec<SpaceSetObject>,
+        space_objects: Vec<SpaceSetObject>,
         scales: Option<Vec<Vec<u32>>>,
         max_elements: Option<usize>,
-    ) -> Self {
+        threads: usize,
+    ) -> Result<Self, Error> {
         //FIXME: Remove hard-coded constants for dimensions & bit length of morton codes.
         const DIMENSIONS: usize = 3;
         const CELL_BITS: usize = 10;
 
-        // Build the set of SpaceIndices.
-        let mut resolutions = vec![];
-        let mut indices = vec![];
+        let threads = threads.max(1);
 
-        if let Some(mut scales) = scales {
-            // We optimize scaling, by iteratively building coarser and coarser
-            // indexes. Powers holds a list of bit shift to apply based on the
-            // previous value.
-            let mut powers = Vec::with_capacity(scales.len());
+        // We optimize scaling, by iteratively building coarser and coarser
+        // indexes. Powers holds a list of bit shift to apply based on the
+        // previous value.
+        let powers = match scales {
+            None => None,
+            Some(mut scales) => {
+                let mut powers = Vec::with_capacity(scales.len());
 
-            // Limit temporary values lifetimes
-            {
                 scales.sort_unstable_by_key(|v| v[0]);
 
                 let mut previous = 0u32;
                 for scale in scales {
-                    // FIXME: Remove these assertions ASAP, and support multi-factor scaling
-                    assert_eq!(scale.len(), DIMENSIONS);
-                    assert!(scale[0] == scale[1] && scale[0] == scale[2]);
+                    // FIXME: Remove these checks ASAP, and support multi-factor scaling
+                    if scale.len() != DIMENSIONS {
+                        return Err(Error::DimensionMismatch(DIMENSIONS, scale.len()));
+                    }
+                    if scale[0] != scale[1] || scale[0] != scale[2] {
+                        return Err(Error::InvalidDefinition(format!(
+                            "Scale factors must be identical on all axes: {:?}",
+                            scale
+                        )));
+                    }
 
                     powers.push((scale[0], scale[0] - previous));
                     previous = scale[0];
                 }
-            }
 
-            // Apply fixed scales
-            let mut count = 0;
-            for power in &powers {
-                space_objects = space_objects
-                    .into_iter()
-                    .map(|mut o| {
-                        let p = o.position().reduce_precision(power.1);
-                        let mut hasher = DefaultHasher::new();
-                        o.set_position(p);
-
-                        // Hash, AFTER updating the position.
-                        o.hash(&mut hasher);
-
-                        (hasher.finish(), o)
-                    })
-                    .collect::<HashMap<_, SpaceSetObject>>()
-                    .drain()
-                    .map(|(_k, v)| v)
-                    .collect();
-
-                // Make sure we do not shift more position than available
-                let shift = if count >= 31 { 31 } else { count };
-                count += 1;
-                indices.push((
-                    SpaceSetIndex::new(space_objects.iter(), DIMENSIONS, CELL_BITS),
-                    vec![power.0, power.0, power.0],
-                    shift,
-                ));
+                Some(powers)
             }
-        } else {
-            // Generate scales, following max_elements
-            if let Some(max_elements) = max_elements {
-                let mut count = 0;
-
-                // The next index should contain at most half the number of
-                // elements of the current index.
-                let mut element_count_target = space_objects.len() / 2;
-
-                // Insert Full resolution index.
-                indices.push((
-                    SpaceSetIndex::new(space_objects.iter(), DIMENSIONS, CELL_BITS),
-                    vec![count, count, count],
-                    0, // Smallest value => highest resolution
-                ));
-
-                // Generate coarser indices, until we reach the expect max_element
-                // values or we can't define bigger bit shift.
-                loop {
-                    // Make sure we do not shift more position than available as well.
-                    if space_objects.len() <= max_elements || count > 31 {
-                        break;
-                    }
-                    let shift = count;
+        };
 
-                    count += 1;
-                    space_objects = space_objects
-                        .into_iter()
-                        .map(|mut o| {
-                            let p = o.position().reduce_precision(1);
-                            let mut hasher = DefaultHasher::new();
-                            o.set_position(p);
+        let mut space_objects = Arc::new(space_objects);
 
-                            // Hash, AFTER updating the position.
-                            o.hash(&mut hasher);
+        // Build the set of SpaceIndices.
+        let indices = thread::scope(|scope| {
+            let mut indices = vec![];
 
-                            (hasher.finish(), o)
-                        })
-                        .collect::<HashMap<_, SpaceSetObject>>()
-                        .drain()
-                        .map(|(_k, v)| v)
-                        .collect();
-
-                    // Skip a resolution if it does not bring down enough the
-                    // number of points. It would be a waste of space to store it.
-                    if element_count_target < space_objects.len() {
-                        continue;
-                    } else {
-                        // The next index should contain at most half the number of
-                        // elements of the current index.
-                        element_count_target = space_objects.len() / 2;
-                    }
+            // Build the index of a resolution, in the background when
+            // allowed, while the next resolution is computed.
+            let mut build = |objects: &Arc<Vec<SpaceSetObject>>, scale: Vec<u32>, shift: u32| {
+                let objects = objects.clone();
+                let build = move || SpaceSetIndex::new(objects.iter(), DIMENSIONS, CELL_BITS);
 
-                    indices.push((
-                        SpaceSetIndex::new(space_objects.iter(), DIMENSIONS, CELL_BITS),
-                        vec![count, count, count],
-                        shift,
-                    ));
+                if threads > 1 {
+                    indices.push((Pending::Building(scope.spawn(build)), scale, shift));
+                } else {
+                    indices.push((Pending::Built(build()), scale, shift));
                 }
+            };
 
-            // Generate indices as long as max is smaller than the number of point located in the whole space.
-            // For each new index, reduce precision by two, and push to resolutions vectors.
+            if let Some(powers) = powers {
+                // Apply fixed scales
+                for (count, power) in powers.iter().enumerate() {
+                    space_objects = Arc::new(reduce_precision(&space_objects, power.1, threads));
+
+                    // Make sure we do not shift more position than available
+                    let shift = if count >= 31 { 31 } else { count as u32 };
+                    build(&space_objects, vec![power.0, power.0, power.0], shift);
+                }
             } else {
-                // Generate only full-scale.
-                indices.push((
-                    SpaceSetIndex::new(space_objects.iter(), DIMENSIONS, CELL_BITS),
-                    vec![0, 0, 0],
-                    0,
-                ));
+                // Generate scales, following max_elements
+                if let Some(max_elements) = max_elements {
+                    let mut count = 0;
+
+                    // The next index should contain at most half the number of
+                    // elements of the current index.
+                    let mut element_count_target = space_objects.len() / 2;
+
+                    // Insert Full resolution index.
+                    build(
+                        &space_objects,
+                        vec![count, count, count],
+                        0, // Smallest value => highest resolution
+                    );
+
+                    // Generate coarser indices, until we reach the expect max_element
+                    // values or we can't define bigger bit shift.
+                    loop {
+                        // Make sure we do not shift more position than available as well.
+                        if space_objects.len() <= max_elements || count > 31 {
+                            break;
+                        }
+                        let shift = count;
+
+                        count += 1;
+                        space_objects = Arc::new(reduce_precision(&space_objects, 1, threads));
+
+                        // Skip a resolution if it does not bring down enough the
+                        // number of points. It would be a waste of space to store it.
+                        if element_count_target < space_objects.len() {
+                            continue;
+                        } else {
+                            // The next index should contain at most half the number of
+                            // elements of the current index.
+                            element_count_target = space_objects.len() / 2;
+                        }
+
+                        build(&space_objects, vec![count, count, count], shift);
+                    }
+
+                // Generate indices as long as max is smaller than the number of point located in the whole space.
+                // For each new index, reduce precision by two, and push to resolutions vectors.
+                } else {
+                    // Generate only full-scale.
+                    build(&space_objects, vec![0, 0, 0], 0);
+                }
             }
-        }
+
+            indices
+                .into_iter()
+                .map(|(index, scale, shift)| {
+                    let index = match index {
+                        Pending::Built(index) => index,
+                        Pending::Building(handle) => handle.join().unwrap(),
+                    };
+
+                    (index, scale, shift)
+                })
+                .collect::<Vec<_>>()
+        });
 
         // When done, go over the array, and set the threshold_volumes with Volume total / 8 * i in reverse order
         let space_volume = reference_space.volume();
@@ -166,6 +261,7 @@ impl SpaceDB {
             Some((_, _, x)) => *x,
         };
 
+        let mut resolutions = vec![];
         for (index, scale, shift) in indices {
             // Compute threshold volume as Vt = V / 2^(max_shift) * 2^shift
             //  => the smaller shift is, the smaller the threshold is and the higher
@@ -183,9 +279,18 @@ impl SpaceDB {
             None => Ordering::Less, // FIXME: This is most likely incorrect...
         });
 
-        SpaceDB {
+        Ok(SpaceDB {
             reference_space: reference_space.name().clone(),
             resolutions,
+        })
+    }
+
+    // Assemble an index from already built resolutions, which must be
+    // sorted from highest to lowest resolution.
+    pub fn from_resolutions(reference_space: &str, resolutions: Vec<SpaceIndex>) -> Self {
+        SpaceDB {
+            reference_space: reference_space.to_string(),
+            resolutions,
         }
     }
 
@@ -193,6 +298,50 @@ impl SpaceDB {
         &self.reference_space
     }
 
+    pub fn resolutions(&self) -> &Vec<SpaceIndex> {
+        &self.resolutions
+    }
+
+    // Retrieve all the objects stored in one of the resolutions. The results
+    // are in encoded space coordinates.
+    pub fn objects(
+        &self,
+        reference_space: &Space,
+        resolution: usize,
+    ) -> Result<Vec<(Position, usize)>, Error> {
+        let index = &self.resolutions[resolution];
+        let shift = index.scale()[0];
+
+        let (low, high) = reference_space.bounding_box();
+        let low = reference_space.encode(&Vec::<f64>::from(low))?;
+        let high = reference_space.encode(&Vec::<f64>::from(high))?;
+
+        Ok(index.find_range(&low.reduce_precision(shift), &high.reduce_precision(shift)))
+    }
+
+    // Add objects to every resolution of the index. The positions are
+    // expected in encoded space coordinates, at full resolution.
+    pub fn insert(&mut self, space_objects: &[SpaceSetObject]) {
+        for resolution in &mut self.resolutions {
+            // The scale holds the total number of bits of precision removed
+            // from the full resolution coordinates for this index.
+            let shift = resolution.scale()[0];
+
+            resolution.insert(space_objects.iter().map(|o| {
+                let mut o = o.clone();
+                o.set_position(o.position().reduce_precision(shift));
+                o
+            }));
+        }
+    }
+
+    // Remove every position linked to `value` from all the resolutions.
+    pub fn remove(&mut self, value: usize) {
+        for resolution in &mut self.resolutions {
+            resolution.remove(value);
+        }
+    }
+
     /* Comment this for now, as this is not yet used.
     // The smallest volume threshold, which is the highest resolution,  will
     // be at position 0
@@ -283,13 +432,13 @@ impl SpaceDB {
         &self,
         id: usize,
         parameters: &CoreQueryParameters,
-    ) -> Result<Vec<Position>, String> {
+    ) -> Result<Vec<Position>, Error> {
         // Is that ID referenced in the current space?
         let index = self.resolution(parameters);
 
         // Convert the view port to the encoded space coordinates
         let space = parameters.db.space(&self.reference_space)?;
-        let view_port = parameters.view_port(space);
+        let view_port = parameters.view_port(&space);
 
         // Select the objects
         let objects = self.resolutions[index].find_by_value(&SpaceFields::new(self.name(), id));
@@ -312,7 +461,21 @@ impl SpaceDB {
         &self,
         positions: &[Position],
         parameters: &CoreQueryParameters,
-    ) -> Result<Vec<(Position, &SpaceFields)>, String> {
+    ) -> Result<Vec<(Position, usize)>, Error> {
+        self.get_by_positions_iter(positions.iter().cloned(), parameters)
+    }
+
+    // Search by positions defining a volume, consumed one at a time, so
+    // that the volume does not have to be kept in memory.
+    // The position is expressed in encoded space coordinates, and results are in encoded space coordinates.
+    pub fn get_by_positions_iter<I>(
+        &self,
+        positions: I,
+        parameters: &CoreQueryParameters,
+    ) -> Result<Vec<(Position, usize)>, Error>
+    where
+        I: Iterator<Item = Position>,
+    {
         let index = self.resolution(parameters);
 
         // FIXME: Should I do it here, or add the assumption this is a clean list?
@@ -321,15 +484,12 @@ impl SpaceDB {
         //let view_port = parameters.view_port(space);
 
         // Select the objects
-        let results = positions
-            .iter()
-            .flat_map(|position| {
-                self.resolutions[index]
-                    .find(position)
-                    .into_iter()
-                    .map(move |fields| (position.clone(), fields))
-            })
-            .collect();
+        let mut results = vec![];
+        for position in positions {
+            for value in self.resolutions[index].find(&position) {
+                results.push((position.clone(), value));
+            }
+        }
 
         Ok(results)
     }
@@ -344,12 +504,12 @@ impl SpaceDB {
         &self,
         shape: &Shape,
         parameters: &CoreQueryParameters,
-    ) -> Result<Vec<(Position, &SpaceFields)>, String> {
+    ) -> Result<Vec<(Position, usize)>, Error> {
         let index = self.resolution(parameters);
 
         // Convert the view port to the encoded space coordinates
         let space = parameters.db.space(&self.reference_space)?;
-        let view_port = parameters.view_port(space);
+        let view_port = parameters.view_port(&space);
 
         // Select the objects
         let results = self.resolutions[index].find_by_shape(&shape, &view_port)?;
@@ -1,12 +1,17 @@
 use std::cmp::Ord;
+use std::cmp::Ordering;
 
 use ironsea_index::IndexedDestructured;
 use serde::Deserialize;
 use serde::Serialize;
+use serde::Serializer;
 
+use super::mapped_index::morton_cmp;
+use super::mapped_index::MappedIndex;
 use super::space::Coordinate;
 use super::space::Position;
 use super::space::Shape;
+use super::Error;
 
 #[derive(Clone, Debug, Hash)]
 pub struct SpaceSetObject {
@@ -99,12 +104,54 @@ impl ironsea_index::RecordFields<SpaceFields> for &SpaceSetObject {
 
 pub type SpaceSetIndex = ironsea_index_sfc_dbc::IndexOwned<SpaceFields, Position, Coordinate>;
 
+// Storage of the positions of a `SpaceIndex`.
+#[derive(Clone, Debug, Deserialize)]
+enum Positions {
+    Owned(SpaceSetIndex),
+    // Memory-mapped indices are stored through `storage::mapped`.
+    #[serde(skip_deserializing)]
+    Mapped(MappedIndex),
+}
+
+impl Serialize for Positions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Positions::Owned(index) => {
+                serializer.serialize_newtype_variant("Positions", 0, "Owned", index)
+            }
+            Positions::Mapped(_) => Err(serde::ser::Error::custom(
+                "Memory-mapped indices can only be stored through storage::mapped",
+            )),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SpaceIndex {
     threshold_volume: f64,
     // lookup_ rounds up, so reverse sort of the list on thresholds and check for last index.
     scale: Vec<u32>,
-    index: SpaceSetIndex,
+    index: Positions,
+    // The SFC index is immutable once built, so objects inserted afterwards
+    // are kept here, sorted along the Z-order curve, and merged in the
+    // results of every lookup.
+    #[serde(default)]
+    inserted: Vec<(Position, usize)>,
+    // The same objects, ordered by value, for the lookups by value.
+    #[serde(default)]
+    inserted_by_value: Vec<(usize, Position)>,
+    // Likewise, the values removed are kept, ordered, in this list, and
+    // filtered out of the results of every lookup.
+    #[serde(default)]
+    removed: Vec<usize>,
+}
+
+// Compare two positions along the Z-order curve.
+fn curve_cmp(a: &Position, b: &Position) -> Ordering {
+    morton_cmp(a.dimensions(), |k| a[k].u64(), |k| b[k].u64())
 }
 
 impl SpaceIndex {
@@ -112,7 +159,21 @@ impl SpaceIndex {
         SpaceIndex {
             threshold_volume,
             scale,
-            index,
+            index: Positions::Owned(index),
+            inserted: vec![],
+            inserted_by_value: vec![],
+            removed: vec![],
+        }
+    }
+
+    pub fn mapped(threshold_volume: f64, scale: Vec<u32>, index: MappedIndex) -> Self {
+        SpaceIndex {
+            threshold_volume,
+            scale,
+            index: Positions::Mapped(index),
+            inserted: vec![],
+            inserted_by_value: vec![],
+            removed: vec![],
         }
     }
 
@@ -124,19 +185,127 @@ impl SpaceIndex {
         &self.scale
     }
 
+    // Add objects to the index, skipping the ones already registered.
+    // The positions are expected at the precision of this index, in encoded
+    // space coordinates.
+    pub fn insert<I>(&mut self, objects: I)
+    where
+        I: Iterator<Item = SpaceSetObject>,
+    {
+        let mut objects = objects
+            .map(|object| (object.position().clone(), object.value()))
+            .filter(|(position, value)| !self.find(position).contains(value))
+            .collect::<Vec<_>>();
+
+        objects.sort_unstable_by(|(a, va), (b, vb)| curve_cmp(a, b).then(va.cmp(vb)));
+        objects.dedup_by(|(a, va), (b, vb)| va == vb && curve_cmp(a, b) == Ordering::Equal);
+
+        // Both lists are made of two sorted runs, which the stable sort
+        // merges in linear time.
+        self.inserted_by_value.extend(
+            objects
+                .iter()
+                .map(|(position, value)| (*value, position.clone())),
+        );
+        self.inserted_by_value.sort_by_key(|(value, _)| *value);
+
+        self.inserted.extend(objects);
+        self.inserted.sort_by(|(a, _), (b, _)| curve_cmp(a, b));
+    }
+
+    // Remove all the objects linked to `value`.
+    pub fn remove(&mut self, value: usize) {
+        self.inserted.retain(|(_, v)| *v != value);
+        self.inserted_by_value.retain(|(v, _)| *v != value);
+
+        if let Err(i) = self.removed.binary_search(&value) {
+            self.removed.insert(i, value);
+        }
+    }
+
+    fn is_removed(&self, value: usize) -> bool {
+        self.removed.binary_search(&value).is_ok()
+    }
+
     // Inputs and Results are expressed in encoded space coordinates.
-    pub fn find(&self, key: &Position) -> Vec<&SpaceFields> {
-        self.index.find(key)
+    pub fn find(&self, key: &Position) -> Vec<usize> {
+        let mut results = match &self.index {
+            Positions::Owned(index) => index
+                .find(key)
+                .into_iter()
+                .map(|fields| fields.value())
+                .collect(),
+            Positions::Mapped(index) => index.find(key),
+        };
+        results.retain(|value| !self.is_removed(*value));
+
+        let first = self
+            .inserted
+            .partition_point(|(position, _)| curve_cmp(position, key) == Ordering::Less);
+        results.extend(
+            self.inserted[first..]
+                .iter()
+                .take_while(|(position, _)| curve_cmp(position, key) == Ordering::Equal)
+                .map(|(_, value)| *value),
+        );
+
+        results
     }
 
     // Inputs and Results are expressed in encoded space coordinates.
-    fn find_range(&self, start: &Position, end: &Position) -> Vec<(Position, &SpaceFields)> {
-        self.index.find_range(start, end)
+    pub fn find_range(&self, start: &Position, end: &Position) -> Vec<(Position, usize)> {
+        let mut results = match &self.index {
+            Positions::Owned(index) => index
+                .find_range(start, end)
+                .into_iter()
+                .map(|(position, fields)| (position, fields.value()))
+                .collect(),
+            Positions::Mapped(index) => index.find_range(start, end),
+        };
+        results.retain(|(_, value)| !self.is_removed(*value));
+
+        // All the positions within the box are located on the curve between
+        // its two corners.
+        let first = self
+            .inserted
+            .partition_point(|(position, _)| curve_cmp(position, start) == Ordering::Less);
+        let last = self
+            .inserted
+            .partition_point(|(position, _)| curve_cmp(position, end) != Ordering::Greater);
+        if first < last {
+            results.extend(
+                self.inserted[first..last]
+                    .iter()
+                    .filter(|(position, _)| start <= position && position <= end)
+                    .cloned(),
+            );
+        }
+
+        results
     }
 
     // Inputs and Results are expressed in encoded space coordinates.
     pub fn find_by_value(&self, id: &SpaceFields) -> Vec<Position> {
-        self.index.find_by_value(id)
+        if self.is_removed(id.value()) {
+            return vec![];
+        }
+
+        let mut results = match &self.index {
+            Positions::Owned(index) => index.find_by_value(id),
+            Positions::Mapped(index) => index.find_by_value(id.value()),
+        };
+
+        let first = self
+            .inserted_by_value
+            .partition_point(|(value, _)| *value < id.value());
+        results.extend(
+            self.inserted_by_value[first..]
+                .iter()
+                .take_while(|(value, _)| *value == id.value())
+                .map(|(_, position)| position.clone()),
+        );
+
+        results
     }
 
     // Inputs and Results are also in encoded space coordinates.
@@ -144,21 +313,21 @@ impl SpaceIndex {
         &self,
         shape: &Shape,
         view_port: &Option<Shape>,
-    ) -> Result<Vec<(Position, &SpaceFields)>, String> {
+    ) -> Result<Vec<(Position, usize)>, Error> {
         match shape {
             Shape::Point(position) => {
                 if let Some(mbb) = view_port {
                     if !mbb.contains(position) {
-                        return Err(format!(
+                        return Err(Error::OutOfBounds(format!(
                             "View port '{:?}' does not contain '{:?}'",
                             mbb, position
-                        ));
+                        )));
                     }
                 }
                 Ok(self
                     .find(position)
                     .into_iter()
-                    .map(|fields| (position.clone(), fields))
+                    .map(|value| (position.clone(), value))
                     .collect())
             }
             Shape::BoundingBox(bl, bh) => {
@@ -169,10 +338,10 @@ impl SpaceIndex {
                             let lower = bl.max(vl);
                             let higher = bh.min(vh);
                             if higher < lower {
-                                Err(format!(
+                                Err(Error::OutOfBounds(format!(
                                     "View port '{:?}' does not intersect '{:?}'",
                                     mbb, shape
-                                ))
+                                )))
                             } else {
                                 trace!(
                                     "mbb {:?} shape {:?} lower {:?} higher {:?}",
@@ -184,13 +353,53 @@ impl SpaceIndex {
                                 Ok(self.find_range(lower, higher))
                             }
                         }
-                        _ => Err(format!("Invalid view port shape '{:?}'", mbb)),
+                        _ => Err(Error::InvalidDefinition(format!(
+                            "Invalid view port shape '{:?}'",
+                            mbb
+                        ))),
                     }
                 } else {
                     Ok(self.find_range(bl, bh))
                 }
             }
-            Shape::HyperSphere(center, radius) => {
+            Shape::Union(shapes) => {
+                // Query each shape separately, as the bounding box of the
+                // union might be mostly empty. Shapes outside of the view
+                // port do not contribute to the union.
+                let mut results = vec![];
+                for member in shapes {
+                    match self.find_by_shape(member, view_port) {
+                        Ok(positions) => results.extend(positions),
+                        Err(Error::OutOfBounds(_)) => (),
+                        Err(e) => return Err(e),
+                    }
+                }
+                // Use the total order, as positions are only partially ordered.
+                results.sort_unstable_by(Ord::cmp);
+                results.dedup();
+
+                Ok(results)
+            }
+            Shape::Capsule(vertices, radius) if vertices.len() > 2 => {
+                // Query each segment separately, as the bounding box of the
+                // whole polyline might be mostly empty.
+                let segments = vertices
+                    .windows(2)
+                    .map(|segment| Shape::Capsule(segment.to_vec(), *radius))
+                    .collect();
+
+                self.find_by_shape(&Shape::Union(segments), view_port)
+            }
+            Shape::Capsule(vertices, _) if vertices.is_empty() => Ok(vec![]),
+            Shape::Intersection(_) | Shape::Difference(_, _) if shape.is_mbb_empty() => Ok(vec![]),
+            Shape::HyperSphere(_, _)
+            | Shape::HyperRectangle(_, _)
+            | Shape::Ellipsoid(_, _)
+            | Shape::Capsule(_, _)
+            | Shape::Mesh(_, _)
+            | Shape::VoxelMask(_)
+            | Shape::Intersection(_)
+            | Shape::Difference(_, _) => {
                 let (bl, bh) = &shape.get_mbb();
                 let lower;
                 let higher;
@@ -202,7 +411,12 @@ impl SpaceIndex {
                             lower = bl.max(vl);
                             higher = bh.min(vh);
                         }
-                        _ => return Err(format!("Invalid view port shape '{:?}'", mbb)),
+                        _ => {
+                            return Err(Error::InvalidDefinition(format!(
+                                "Invalid view port shape '{:?}'",
+                                mbb
+                            )))
+                        }
                     }
                 } else {
                     lower = bl;
@@ -210,12 +424,12 @@ impl SpaceIndex {
                 }
 
                 // Filter out results using using a range query over the MBB,
-                // then add the condition of the radius as we are working within
-                // a sphere.
+                // then add the condition of the shape itself, as the MBB
+                // covers more than the shape.
                 let results = self
                     .find_range(&lower, &higher)
                     .into_iter()
-                    .filter(|(position, _)| (position - center).norm() <= radius.f64())
+                    .filter(|(position, _)| shape.contains(position))
                     .collect();
 
                 Ok(results)
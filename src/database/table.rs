@@ -0,0 +1,182 @@
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::OnceLock;
+
+use serde::de::DeserializeOwned;
+use serde::ser::Error as _;
+use serde::ser::SerializeSeq;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use super::mapped_index::MappedTable;
+use super::Error;
+
+// Number of values in each chunk of cells.
+const CHUNK: usize = 1024;
+
+// Values stored at consecutive offsets.
+//
+// The values of a memory-mapped table are only deserialized when they are
+// accessed for the first time, and the cells holding them are allocated
+// by chunks, when one of their values is first accessed, so that loading
+// an index does not depend on the size of the table. Values added
+// afterwards are kept in memory.
+#[derive(Clone)]
+pub struct Table<T> {
+    chunks: Vec<OnceLock<Box<[OnceLock<T>]>>>,
+    len: usize,
+    mapped: Option<MappedTable>,
+}
+
+impl<T> Table<T> {
+    fn chunk() -> Box<[OnceLock<T>]> {
+        (0..CHUNK).map(|_| OnceLock::new()).collect()
+    }
+
+    fn cell(&self, offset: usize) -> &OnceLock<T> {
+        &self.chunks[offset / CHUNK].get_or_init(Self::chunk)[offset % CHUNK]
+    }
+
+    fn cell_mut(&mut self, offset: usize) -> &mut OnceLock<T> {
+        self.cell(offset);
+
+        match self.chunks[offset / CHUNK].get_mut() {
+            None => unreachable!(),
+            Some(chunk) => &mut chunk[offset % CHUNK],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn push(&mut self, value: T) {
+        // Add a chunk when the existing ones are full.
+        if self.chunks.len() * CHUNK == self.len {
+            self.chunks.push(OnceLock::new());
+        }
+
+        self.len += 1;
+        *self.cell_mut(self.len - 1) = OnceLock::from(value);
+    }
+
+    // Replace the value stored at `offset`, without reading it.
+    pub fn set(&mut self, offset: usize, value: T) {
+        *self.cell_mut(offset) = OnceLock::from(value);
+    }
+}
+
+impl<T> Table<T>
+where
+    T: DeserializeOwned,
+{
+    pub fn mapped(table: MappedTable) -> Self {
+        let len = table.len();
+
+        Table {
+            chunks: (0..len.div_ceil(CHUNK)).map(|_| OnceLock::new()).collect(),
+            len,
+            mapped: Some(table),
+        }
+    }
+
+    pub fn get(&self, offset: usize) -> Result<&T, Error> {
+        // Offsets read from a corrupted index may be out of range.
+        if offset >= self.len {
+            return Err(Error::Storage(format!("Invalid table entry {}", offset)));
+        }
+
+        let cell = self.cell(offset);
+        if let Some(value) = cell.get() {
+            return Ok(value);
+        }
+
+        // Values missing from the cells come from the mapped table. If
+        // another thread reads it in the meantime, both values are equal.
+        let value = match &self.mapped {
+            None => unreachable!(),
+            Some(table) => table.get(offset)?,
+        };
+
+        Ok(cell.get_or_init(|| value))
+    }
+
+    pub fn get_mut(&mut self, offset: usize) -> Result<&mut T, Error> {
+        self.get(offset)?;
+
+        match self.cell_mut(offset).get_mut() {
+            None => unreachable!(),
+            Some(value) => Ok(value),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Result<&T, Error>> {
+        (0..self.len()).map(move |offset| self.get(offset))
+    }
+}
+
+impl<T> From<Vec<T>> for Table<T> {
+    fn from(values: Vec<T>) -> Self {
+        let mut table = Table {
+            chunks: Vec::with_capacity(values.len().div_ceil(CHUNK)),
+            len: 0,
+            mapped: None,
+        };
+
+        for value in values {
+            table.push(value);
+        }
+
+        table
+    }
+}
+
+impl<T> Debug for Table<T>
+where
+    T: Debug + DeserializeOwned,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut list = f.debug_list();
+        for value in self.iter() {
+            match value {
+                Ok(value) => list.entry(value),
+                Err(e) => list.entry(&e),
+            };
+        }
+
+        list.finish()
+    }
+}
+
+// Tables are stored as lists, whether they are memory-mapped or not.
+impl<T> Serialize for Table<T>
+where
+    T: DeserializeOwned + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for value in self.iter() {
+            seq.serialize_element(value.map_err(S::Error::custom)?)?;
+        }
+
+        seq.end()
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Table<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Vec::deserialize(deserializer)?.into())
+    }
+}
@@ -0,0 +1,145 @@
+use std::fs::File;
+use std::sync::Arc;
+
+use memmap::Mmap;
+
+use super::mapped_index::MappedIndex;
+use super::mapped_index::MappedTable;
+use super::table::Table;
+use super::*;
+
+// Write a section to a temporary file, and map it in memory.
+fn map<F>(name: &str, write: F) -> Arc<Mmap>
+where
+    F: FnOnce(&mut File),
+{
+    let path = std::env::temp_dir().join(format!("mercator_db-{}-{}", std::process::id(), name));
+
+    write(&mut File::create(&path).unwrap());
+    let data = unsafe { Mmap::map(&File::open(&path).unwrap()).unwrap() };
+    std::fs::remove_file(&path).unwrap();
+
+    Arc::new(data)
+}
+
+fn get_mapped_index(name: &str) -> MappedIndex {
+    // Every position of a 4x4 grid, in row order, with one value per row.
+    let records = (0..16u64)
+        .map(|i| (vec![i % 4, i / 4].into(), (i / 4) as usize))
+        .collect::<Vec<(Position, usize)>>();
+
+    let data = map(name, |file| {
+        MappedIndex::write(file, 2, 4, records).unwrap();
+    });
+
+    MappedIndex::new(data, 0).unwrap()
+}
+
+#[test]
+pub fn mapped_index_order() {
+    let index = get_mapped_index("order");
+
+    // The records are sorted along the Z-order curve, which is the order
+    // of the results of a range query.
+    let order = index
+        .find_range(&vec![0u64, 0].into(), &vec![3u64, 3].into())
+        .into_iter()
+        .map(|(position, _)| (position[0].u64(), position[1].u64()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        order,
+        vec![
+            (0, 0),
+            (0, 1),
+            (1, 0),
+            (1, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3),
+            (2, 0),
+            (2, 1),
+            (3, 0),
+            (3, 1),
+            (2, 2),
+            (2, 3),
+            (3, 2),
+            (3, 3)
+        ]
+    );
+}
+
+#[test]
+pub fn mapped_index_lookups() {
+    let index = get_mapped_index("lookups");
+
+    assert_eq!(index.find(&vec![2u64, 1].into()), vec![1]);
+    assert_eq!(index.find(&vec![2u64, 1, 0].into()), Vec::<usize>::new());
+
+    // The range (1, 1) - (2, 2) spans records outside of it along the curve.
+    let range = index
+        .find_range(&vec![1u64, 1].into(), &vec![2u64, 2].into())
+        .into_iter()
+        .map(|(position, value)| (position[0].u64(), position[1].u64(), value))
+        .collect::<Vec<_>>();
+    assert_eq!(range, vec![(1, 1, 1), (1, 2, 2), (2, 1, 1), (2, 2, 2)]);
+
+    let mut row = index
+        .find_by_value(3)
+        .into_iter()
+        .map(|position| position[0].u64())
+        .collect::<Vec<_>>();
+    row.sort_unstable();
+    assert_eq!(row, vec![0, 1, 2, 3]);
+    assert!(index.find_by_value(4).is_empty());
+}
+
+#[test]
+pub fn mapped_table() {
+    let values = vec![
+        Properties::Feature("b".to_string()),
+        Properties::Unknown("a".to_string(), "Cell".to_string()),
+    ];
+
+    let data = map("table", |file| {
+        MappedTable::write(file, values.iter()).unwrap();
+    });
+    let table = MappedTable::new(data.clone(), 0).unwrap();
+
+    assert_eq!(table.len(), 2);
+    assert_eq!(table.get::<Properties>(0).unwrap(), values[0]);
+    assert_eq!(table.get::<Properties>(1).unwrap(), values[1]);
+
+    // Truncated sections are rejected.
+    assert!(MappedTable::new(data.clone(), data.len() - 4).is_err());
+}
+
+#[test]
+pub fn lazy_table() {
+    let values = (0..3000u64).collect::<Vec<_>>();
+    let data = map("lazy_table", |file| {
+        MappedTable::write(file, values.iter()).unwrap();
+    });
+
+    let mut table: Table<u64> = Table::mapped(MappedTable::new(data.clone(), 0).unwrap());
+    assert_eq!(table.len(), 3000);
+    assert_eq!(*table.get(2500).unwrap(), 2500);
+    assert_eq!(*table.get(0).unwrap(), 0);
+
+    // Values are added after the mapped ones, and can be replaced.
+    table.push(3000);
+    table.set(1, 10);
+    *table.get_mut(2).unwrap() += 10;
+    assert_eq!(table.len(), 3001);
+    assert_eq!(
+        table.iter().take(3).collect::<Result<Vec<_>, _>>().unwrap(),
+        vec![&0, &10, &12]
+    );
+    assert_eq!(*table.get(3000).unwrap(), 3000);
+
+    // Invalid offsets and entries are reported instead of panicking.
+    assert!(table.get(3001).is_err());
+    let table: Table<Properties> = Table::mapped(MappedTable::new(data, 0).unwrap());
+    assert!(table.get(0).is_err());
+}
@@ -24,7 +24,7 @@ fn main() {
     // Build a Database Index:
     if true {
         info_time!("Building database index");
-        storage::bincode::build("10k", "v0.1", None, None).unwrap();
+        storage::bincode::build("10k", "v0.1", None, None, None).unwrap();
     }
 
     // Load a Database:
@@ -44,8 +44,9 @@ fn main() {
             threshold_volume: Some(std::f64::MAX),
             view_port: &None,
             resolution: &None,
+            filters: &[],
         };
-        let r = core.get_by_id(&c, id).unwrap();
+        let r = core.get_by_id(&c, id, None).unwrap();
         println!("get_by_id {}: {}", id, r.len());
         println!("{}: {:?}\n", id, r[0].1[0]);
 
@@ -55,8 +56,9 @@ fn main() {
             threshold_volume: Some(0.0),
             view_port: &None,
             resolution: &None,
+            filters: &[],
         };
-        let r = core.get_by_id(&c, id).unwrap();
+        let r = core.get_by_id(&c, id, None).unwrap();
         println!("get_by_id {}: {}", id, r.len());
         println!("{}: {:?}\n", id, r[0].1[0]);
 
@@ -66,8 +68,9 @@ fn main() {
             threshold_volume: Some(std::f64::MAX),
             view_port: &None,
             resolution: &None,
+            filters: &[],
         };
-        let r = core.get_by_label(&c, id).unwrap();
+        let r = core.get_by_label(&c, id, None).unwrap();
         println!("get_by_label {}: {}", id, r.len());
         if !r.is_empty() {
             println!("{}: {:?}\n", id, r); // no overlaping point, so no results
@@ -84,6 +87,7 @@ fn main() {
             threshold_volume: Some(0.0),
             view_port: &None,
             resolution: &None,
+            filters: &[],
         };
         let r = core.get_by_shape(&c, &shape, "std").unwrap();
         println!("get_by_shape {:?}: {}", shape, r.len());
@@ -134,7 +138,7 @@ fn main() {
 
         println!(
             "\nSPACE OBJECT:\n\n{}",
-            serde_json::to_string_pretty(space).unwrap()
+            serde_json::to_string_pretty(space.as_ref()).unwrap()
         );
         //FIXME: Not returning SpatialObjects by default
         println!(
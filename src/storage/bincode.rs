@@ -1,18 +1,30 @@
 //! Bincode support
 
+use std::convert::TryInto;
 use std::fs::File;
+use std::io::BufRead;
+use std::io::BufReader;
 use std::io::BufWriter;
 use std::io::Error;
 use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
 
+use bincode::Options;
 use memmap::Mmap;
 use serde::de::DeserializeOwned;
+use serde::Deserialize;
 use serde::Serialize;
 
 use super::model;
+use crate::space::Space;
+use crate::Core;
 
 /// Deserialize a data structure.
 ///
+/// The header written by [store](fn.store.html) is validated. Files
+/// without header, written by older versions, are still accepted.
+///
 /// # Parameters
 ///
 ///  * `from`:
@@ -25,7 +37,13 @@ where
 
     let mmap = unsafe { Mmap::map(&file_in)? };
 
-    match bincode::deserialize(&mmap[..]) {
+    let data = if mmap.starts_with(MAGIC) {
+        split_header(&mmap[..])?.1
+    } else {
+        &mmap[..]
+    };
+
+    match bincode::deserialize(data) {
         Ok(data) => Ok(data),
         Err(e) => Err(Error::new(
             ErrorKind::InvalidData,
@@ -34,7 +52,170 @@ where
     }
 }
 
-/// Serialize a data structure.
+/// Deserialize a list, one element at a time.
+///
+/// Unlike [load](fn.load.html), the list is never stored in memory as a
+/// whole, which allows processing files larger than the memory.
+///
+/// # Parameters
+///
+///  * `from`:
+///      File to read, which contains a Bincode list.
+///
+///  * `f`:
+///      Function called on each element, in order. Processing stops at
+///      the first error.
+pub fn for_each<T, F>(from: &str, f: F) -> Result<(), Error>
+where
+    T: DeserializeOwned,
+    F: FnMut(T) -> Result<(), Error>,
+{
+    for_each_with::<T, T, F>(from, f)
+}
+
+/// Deserialize a list of objects, one element at a time.
+///
+/// Objects stored without header predate the attributes of the
+/// *identifiers*, and are read with their former layout, as bincode
+/// cannot fill in missing fields.
+///
+/// # Parameters
+///
+///  * `from`:
+///      File to read, which contains a Bincode list of objects.
+///
+///  * `f`:
+///      Function called on each object, in order. Processing stops at
+///      the first error.
+pub fn for_each_object<F>(from: &str, f: F) -> Result<(), Error>
+where
+    F: FnMut(model::SpatialObject) -> Result<(), Error>,
+{
+    for_each_with::<model::SpatialObject, LegacySpatialObject, F>(from, f)
+}
+
+/// Deserialize a list of objects.
+///
+/// See [for_each_object](fn.for_each_object.html) for the files stored
+/// without header.
+///
+/// # Parameters
+///
+///  * `from`:
+///      File to read, which contains a Bincode list of objects.
+pub fn load_objects(from: &str) -> Result<Vec<model::SpatialObject>, Error> {
+    let mut objects = vec![];
+    for_each_object(from, |object| {
+        objects.push(object);
+        Ok(())
+    })?;
+
+    Ok(objects)
+}
+
+// Layout of the objects stored before the attributes were introduced.
+#[derive(Deserialize)]
+struct LegacyProperties {
+    type_name: String,
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct LegacySpatialObject {
+    properties: LegacyProperties,
+    shapes: Vec<model::v1::Shape>,
+}
+
+impl From<LegacySpatialObject> for model::SpatialObject {
+    fn from(object: LegacySpatialObject) -> Self {
+        model::SpatialObject {
+            properties: model::Properties {
+                type_name: object.properties.type_name,
+                id: object.properties.id,
+                attributes: Default::default(),
+            },
+            shapes: object.shapes,
+        }
+    }
+}
+
+// Deserialize a list, one element at a time. The elements of files
+// without header are read as `L`, then converted.
+fn for_each_with<T, L, F>(from: &str, mut f: F) -> Result<(), Error>
+where
+    T: DeserializeOwned,
+    L: DeserializeOwned + Into<T>,
+    F: FnMut(T) -> Result<(), Error>,
+{
+    let invalid = |e| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("Bincode could not deserialize: {:?}", e),
+        )
+    };
+
+    let mut reader = BufReader::new(File::open(from)?);
+
+    // The checksum can only be verified once the whole list is read.
+    let header = if reader.fill_buf()?.starts_with(MAGIC) {
+        reader.consume(MAGIC.len());
+        Some(read_header(&mut reader)?)
+    } else {
+        None
+    };
+    let mut reader = Checked {
+        reader,
+        length: 0,
+        checksum: FNV_OFFSET,
+    };
+
+    // Lists are stored as their length, followed by the elements.
+    let length: u64 = bincode::deserialize_from(&mut reader).map_err(invalid)?;
+    for _ in 0..length {
+        let element = match header {
+            Some(_) => bincode::deserialize_from(&mut reader).map_err(invalid)?,
+            None => bincode::deserialize_from::<_, L>(&mut reader)
+                .map_err(invalid)?
+                .into(),
+        };
+        f(element)?;
+    }
+
+    match header {
+        Some(header) if (reader.length, reader.checksum) != (header.length, header.checksum) => {
+            Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Corrupted file: expected {} bytes with checksum {:#018x}, read {} bytes with checksum {:#018x}",
+                    header.length, header.checksum, reader.length, reader.checksum
+                ),
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+// Reader computing the checksum of the data read.
+struct Checked<R> {
+    reader: R,
+    length: u64,
+    checksum: u64,
+}
+
+impl<R> Read for Checked<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        let n = self.reader.read(buf)?;
+        self.length += n as u64;
+        self.checksum = update_checksum(self.checksum, &buf[..n]);
+
+        Ok(n)
+    }
+}
+
+/// Serialize a data structure, preceded by a [Header](struct.Header.html).
 ///
 /// # Parameters
 ///
@@ -47,18 +228,257 @@ pub fn store<T>(data: T, to: &str) -> Result<(), Error>
 where
     T: Serialize,
 {
+    store_with_header(&data, None, None, to)
+}
+
+/// Identifies index files.
+pub const MAGIC: &[u8; 8] = b"MRCTRIDX";
+
+/// Version of the layout of the index files.
+///
+/// Version 2 stores the attributes of the *identifiers*. Indices of
+/// version 1, as well as those written before headers were introduced,
+/// cannot be read anymore and have to be rebuilt.
+pub const FORMAT_VERSION: u32 = 2;
+
+/// Description of an index file, stored after `MAGIC` at the start of
+/// the file.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Header {
+    format_version: u32,
+    crate_version: String,
+    scales: Option<Vec<Vec<u32>>>,
+    max_elements: Option<usize>,
+    // Size in bytes and checksum of the index, which follows the header.
+    length: u64,
+    checksum: u64,
+}
+
+impl Header {
+    /// Version of the layout of the file.
+    pub fn format_version(&self) -> u32 {
+        self.format_version
+    }
+
+    /// Version of the crate which wrote the file.
+    pub fn crate_version(&self) -> &String {
+        &self.crate_version
+    }
+
+    /// Resolutions requested when building the index.
+    pub fn scales(&self) -> &Option<Vec<Vec<u32>>> {
+        &self.scales
+    }
+
+    /// Maximum number of elements requested when building the index.
+    pub fn max_elements(&self) -> Option<usize> {
+        self.max_elements
+    }
+}
+
+// Upper bound of the size of a header, so that a corrupted one cannot
+// trigger large allocations.
+const HEADER_LIMIT: u64 = 64 * 1024;
+
+// Headers are serialized with the same encoding as `bincode::serialize`.
+fn header_options() -> impl Options {
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .allow_trailing_bytes()
+        .with_limit(HEADER_LIMIT)
+}
+
+// Read the header following `MAGIC`, and check its format version.
+fn read_header<R>(reader: R) -> Result<Header, Error>
+where
+    R: Read,
+{
+    let invalid = |reason: String| Error::new(ErrorKind::InvalidData, reason);
+
+    let header: Header = match header_options().deserialize_from(reader) {
+        Ok(header) => header,
+        Err(e) => return Err(invalid(format!("Truncated or corrupted header: {:?}", e))),
+    };
+
+    if header.format_version < FORMAT_VERSION {
+        return Err(invalid(format!(
+            "Format version {} (written by version {}) is not supported anymore, expected {}, the file has to be rebuilt",
+            header.format_version, header.crate_version, FORMAT_VERSION
+        )));
+    } else if header.format_version != FORMAT_VERSION {
+        return Err(invalid(format!(
+            "Unsupported format version {} (written by version {}), expected {}",
+            header.format_version, header.crate_version, FORMAT_VERSION
+        )));
+    }
+
+    Ok(header)
+}
+
+// 64-bit FNV-1a hash, used to detect corrupted files.
+pub(super) const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+pub(super) fn update_checksum(hash: u64, data: &[u8]) -> u64 {
+    data.iter().fold(hash, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+pub(super) fn checksum(data: &[u8]) -> u64 {
+    update_checksum(FNV_OFFSET, data)
+}
+
+// Split an index file in its header and the serialized index.
+fn split_header(data: &[u8]) -> Result<(Header, &[u8]), Error> {
+    let invalid = |reason: String| Error::new(ErrorKind::InvalidData, reason);
+
+    if data.len() < MAGIC.len() || &data[0..MAGIC.len()] != MAGIC {
+        return Err(invalid("Not an index file, magic number not found".into()));
+    }
+
+    let mut data = &data[MAGIC.len()..];
+    let header = read_header(&mut data)?;
+
+    if (data.len() as u64) < header.length {
+        return Err(invalid(format!(
+            "Truncated index: expected {} bytes, found {}",
+            header.length,
+            data.len()
+        )));
+    }
+
+    let data = &data[..header.length as usize];
+    if checksum(data) != header.checksum {
+        return Err(invalid(format!(
+            "Corrupted index: checksum mismatch, expected {:#018x}, computed {:#018x}",
+            header.checksum,
+            checksum(data)
+        )));
+    }
+
+    Ok((header, data))
+}
+
+/// Read the header of an index file.
+///
+/// The checksum of the index is verified.
+///
+/// # Parameters
+///
+///  * `from`:
+///      Index file to read.
+pub fn load_header(from: &str) -> Result<Header, Error> {
+    let file_in = File::open(from)?;
+
+    let mmap = unsafe { Mmap::map(&file_in)? };
+
+    Ok(split_header(&mmap[..])?.0)
+}
+
+/// Load an index, after validating its header.
+///
+/// Indices written without header, or with an older format version, are
+/// rejected, see [FORMAT_VERSION](constant.FORMAT_VERSION.html).
+///
+/// # Parameters
+///
+///  * `from`:
+///      Index file to read.
+pub fn load_index(from: &str) -> Result<(Vec<Space>, Core), Error> {
+    let file_in = File::open(from)?;
+
+    let mmap = unsafe { Mmap::map(&file_in)? };
+
+    if !mmap.starts_with(MAGIC) {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "Index has no header, it was written by an older version and has to be rebuilt",
+        ));
+    }
+
+    let (header, data) = split_header(&mmap[..])?;
+    debug!(
+        "Index {} written by version {}, format {}",
+        from, header.crate_version, header.format_version
+    );
+
+    match bincode::deserialize(data) {
+        Ok(index) => Ok(index),
+        Err(e) => Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("Bincode could not deserialize: {:?}", e),
+        )),
+    }
+}
+
+/// Store an index, preceded by its header.
+///
+/// # Parameters
+///
+///  * `spaces`:
+///      Reference spaces used by the index.
+///
+///  * `core`:
+///      Dataset to store.
+///
+///  * `scales`, `max_elements`:
+///      Parameters used to build the index, see [build](fn.build.html).
+///
+///  * `to`:
+///      File to use to store the index.
+pub fn store_index(
+    spaces: &[Space],
+    core: &Core,
+    scales: Option<Vec<Vec<u32>>>,
+    max_elements: Option<usize>,
+    to: &str,
+) -> Result<(), Error> {
+    store_with_header(&(spaces, core), scales, max_elements, to)
+}
+
+// Serialize `data`, preceded by `MAGIC` and its header.
+fn store_with_header<T>(
+    data: &T,
+    scales: Option<Vec<Vec<u32>>>,
+    max_elements: Option<usize>,
+    to: &str,
+) -> Result<(), Error>
+where
+    T: Serialize,
+{
+    let data = match bincode::serialize(data) {
+        Ok(data) => data,
+        Err(e) => {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Bincode could not serialize: {:?}", e),
+            ))
+        }
+    };
+
+    let header = Header {
+        format_version: FORMAT_VERSION,
+        crate_version: env!("CARGO_PKG_VERSION").to_string(),
+        scales,
+        max_elements,
+        length: data.len() as u64,
+        checksum: checksum(&data),
+    };
+
     let file_out = File::create(to)?;
 
     // We create a buffered writer from the file we get
-    let writer = BufWriter::new(&file_out);
+    let mut writer = BufWriter::new(&file_out);
 
-    match bincode::serialize_into(writer, &data) {
-        Ok(()) => Ok(()),
-        Err(e) => Err(Error::new(
+    writer.write_all(MAGIC)?;
+    if let Err(e) = header_options().serialize_into(&mut writer, &header) {
+        return Err(Error::new(
             ErrorKind::InvalidData,
             format!("Bincode could not serialize: {:?}", e),
-        )),
+        ));
     }
+    writer.write_all(&data)?;
+    writer.flush()
 }
 
 /// Build an index from the input files.
@@ -86,33 +506,67 @@ where
 ///     equal to the number of distinct Ids, or smaller or equal to this
 ///     value.
 ///
+/// * `threads`:
+///     The number of threads to use to build the index, or the available
+///     parallelism when `None`. The index is the same whatever the
+///     number of threads.
+///
 /// **Note**: `max_elements` is ignored when `scales` is not `None`.
 pub fn build(
     name: &str,
     version: &str,
     scales: Option<Vec<Vec<u32>>>,
     max_elements: Option<usize>,
+    threads: Option<usize>,
 ) -> Result<(), Error> {
-    let fn_spaces = format!("{}.spaces.bin", name);
-    let fn_objects = format!("{}.objects.bin", name);
     let fn_index = format!("{}.index", name);
 
-    let spaces = load::<Vec<model::Space>>(&fn_spaces)?
-        .iter()
-        .map(|s| s.into())
-        .collect::<Vec<_>>();
+    let (spaces, core) = build_core(name, version, scales.clone(), max_elements, threads)?;
 
-    let objects = load::<Vec<model::SpatialObject>>(&fn_objects)?;
+    store_index(&spaces, &core, scales, max_elements, &fn_index)
+}
 
-    let core = match model::build_index(name, version, &spaces, &objects, scales, max_elements) {
-        Ok(core) => core,
+// Load the `.spaces.bin` and `.objects.bin` input files, and build the
+// index. See `build` for the parameters.
+pub(crate) fn build_core(
+    name: &str,
+    version: &str,
+    scales: Option<Vec<Vec<u32>>>,
+    max_elements: Option<usize>,
+    threads: Option<usize>,
+) -> Result<(Vec<Space>, Core), Error> {
+    let fn_spaces = format!("{}.spaces.bin", name);
+    let fn_objects = format!("{}.objects.bin", name);
+
+    let spaces = match load::<Vec<model::Space>>(&fn_spaces)?
+        .iter()
+        .map(|s| s.try_into())
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(spaces) => spaces,
         Err(e) => {
             return Err(Error::new(
                 ErrorKind::InvalidData,
-                format!("Failure to build index: {:?}", e),
+                format!("Invalid reference space: {}", e),
             ))
         }
     };
 
-    store((spaces, core), &fn_index)
+    let objects = load_objects(&fn_objects)?;
+
+    match model::build_index(
+        name,
+        version,
+        &spaces,
+        &objects,
+        scales,
+        max_elements,
+        threads,
+    ) {
+        Ok(core) => Ok((spaces, core)),
+        Err(e) => Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("Failure to build index: {:?}", e),
+        )),
+    }
 }
@@ -1,12 +1,18 @@
 //! JSON support
 
+use std::fmt;
+use std::fmt::Formatter;
 use std::fs::File;
-use std::io::BufWriter;
+use std::io::BufReader;
 use std::io::Error;
-use std::io::ErrorKind;
+use std::marker::PhantomData;
 
 use memmap::Mmap;
+use serde::de;
 use serde::de::DeserializeOwned;
+use serde::de::SeqAccess;
+use serde::de::Visitor;
+use serde::Deserializer;
 use serde::Serialize;
 
 fn convert<T>(from: &str, to: &str) -> Result<(), Error>
@@ -14,21 +20,11 @@ where
     T: Serialize + DeserializeOwned,
 {
     let file_in = File::open(from)?;
-    let file_out = File::create(to)?;
-
-    // We create a buffered writer from the file we get
-    let writer = BufWriter::new(&file_out);
 
     let mmap = unsafe { Mmap::map(&file_in)? };
     let v: T = serde_json::from_slice(&mmap[..])?;
 
-    match bincode::serialize_into(writer, &v) {
-        Ok(()) => Ok(()),
-        Err(e) => Err(Error::new(
-            ErrorKind::InvalidData,
-            format!("Bincode could not serialize: {:?}", e),
-        )),
-    }
+    super::bincode::store(v, to)
 }
 
 /// Deserialise a JSON file.
@@ -49,3 +45,61 @@ where
 
     convert::<T>(&fn_in, &fn_out)
 }
+
+// Calls a function on each element of a list, as it is deserialised.
+struct ForEach<T, F> {
+    f: F,
+    element: PhantomData<T>,
+}
+
+impl<'de, T, F> Visitor<'de> for ForEach<T, F>
+where
+    T: DeserializeOwned,
+    F: FnMut(T) -> Result<(), Error>,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a list")
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        while let Some(element) = seq.next_element()? {
+            (self.f)(element).map_err(de::Error::custom)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Deserialise a JSON list, one element at a time.
+///
+/// The list is never stored in memory as a whole, which allows
+/// processing files larger than the memory.
+///
+/// # Parameters
+///
+///  * `from`:
+///      File to read, which contains a JSON list.
+///
+///  * `f`:
+///      Function called on each element, in order. Processing stops at
+///      the first error.
+pub fn for_each<T, F>(from: &str, f: F) -> Result<(), Error>
+where
+    T: DeserializeOwned,
+    F: FnMut(T) -> Result<(), Error>,
+{
+    let mut deserializer = serde_json::Deserializer::from_reader(BufReader::new(File::open(from)?));
+
+    deserializer.deserialize_seq(ForEach {
+        f,
+        element: PhantomData,
+    })?;
+    deserializer.end()?;
+
+    Ok(())
+}
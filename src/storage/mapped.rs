@@ -0,0 +1,443 @@
+//! Memory-mapped index support
+//!
+//! Indices stored in this format are queried directly from the mapped
+//! file, instead of being deserialized in memory. Loading them only
+//! reads the header and the footer, and the pages are shared between the
+//! processes using the same index. The checksum of the file is verified
+//! on demand, by [verify](fn.verify.html).
+//!
+//! The file starts with a fixed-size header, containing `MAGIC`, the
+//! format version, and the size and checksum of the rest of the file.
+//! It is followed by one section per resolution, containing the positions sorted along the
+//! space filling curve, and the identifier to positions postings. Then
+//! come the property table, ordered by identifier, and the attributes
+//! table, whose entries are only deserialized when they are used. The
+//! reference spaces, the location of the sections and the parameters used
+//! to build the index are stored, using bincode, at the end of the file. The last eight bytes contain the
+//! offset of this footer.
+
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::Error;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::sync::Arc;
+
+use memmap::Mmap;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::bincode::build_core;
+use super::bincode::checksum;
+use super::bincode::update_checksum;
+use super::bincode::FNV_OFFSET;
+use crate::database::mapped_index::MappedIndex;
+use crate::database::mapped_index::MappedTable;
+use crate::database::space_db::SpaceDB;
+use crate::database::space_index::SpaceIndex;
+use crate::space::Space;
+use crate::Core;
+
+/// Identifies memory-mapped index files.
+pub const MAGIC: &[u8; 8] = b"MRCTRMAP";
+
+/// Version of the layout of the memory-mapped index files.
+pub const FORMAT_VERSION: u64 = 4;
+
+// Size of the header, which contains `MAGIC`, `FORMAT_VERSION`, and the
+// size and checksum of the data following the header.
+pub(super) const HEADER: usize = 32;
+
+#[derive(Deserialize, Serialize)]
+pub(super) struct Resolution {
+    pub(super) threshold_volume: f64,
+    pub(super) scale: Vec<u32>,
+    pub(super) offset: usize,
+}
+
+#[derive(Deserialize, Serialize)]
+pub(super) struct SpaceSection {
+    pub(super) reference_space: String,
+    pub(super) resolutions: Vec<Resolution>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub(super) struct Footer {
+    pub(super) title: String,
+    pub(super) version: String,
+    // Version of the crate which wrote the file, and parameters used to
+    // build the index.
+    pub(super) crate_version: String,
+    pub(super) scales: Option<Vec<Vec<u32>>>,
+    pub(super) max_elements: Option<usize>,
+    pub(super) spaces: Vec<Space>,
+    // Offsets of the property and attributes tables.
+    pub(super) properties: usize,
+    pub(super) attributes: usize,
+    pub(super) space_db: Vec<SpaceSection>,
+}
+
+pub(super) fn invalid_data<E>(e: E) -> Error
+where
+    E: std::fmt::Display,
+{
+    Error::new(ErrorKind::InvalidData, e.to_string())
+}
+
+// Write `MAGIC` and the format version, followed by room for the size
+// and checksum of the file, filled in by `seal`. The header takes
+// `HEADER` bytes.
+pub(super) fn write_header<W>(writer: &mut W) -> Result<(), Error>
+where
+    W: Write,
+{
+    writer.write_all(MAGIC)?;
+    writer.write_all(&FORMAT_VERSION.to_le_bytes())?;
+    writer.write_all(&[0u8; HEADER - 16])
+}
+
+// Store the size and checksum of the data following the header, once the
+// whole file has been written.
+pub(super) fn seal(name: &str) -> Result<(), Error> {
+    let mut file = OpenOptions::new().read(true).write(true).open(name)?;
+    file.seek(SeekFrom::Start(HEADER as u64))?;
+
+    let mut reader = BufReader::new(&mut file);
+    let mut length = 0u64;
+    let mut checksum = FNV_OFFSET;
+    let mut buffer = vec![0u8; 1 << 16];
+    loop {
+        match reader.read(&mut buffer)? {
+            0 => break,
+            n => {
+                length += n as u64;
+                checksum = update_checksum(checksum, &buffer[..n]);
+            }
+        }
+    }
+
+    file.seek(SeekFrom::Start(16))?;
+    file.write_all(&length.to_le_bytes())?;
+    file.write_all(&checksum.to_le_bytes())?;
+    file.sync_all()
+}
+
+// Write the property table, whose entries have to be ordered by
+// identifier, and the attributes table, starting at `position` in the
+// file. Returns the offsets of the two tables, and the position following
+// them.
+pub(super) fn write_tables<W, P, A>(
+    writer: &mut W,
+    properties: P,
+    attributes: A,
+    position: usize,
+) -> Result<(usize, usize, usize), Error>
+where
+    W: Write,
+    P: Iterator,
+    P::Item: Serialize,
+    A: Iterator,
+    A::Item: Serialize,
+{
+    let size = MappedTable::write(writer, properties).map_err(invalid_data)?;
+    let attributes_offset = position + size;
+    let size = MappedTable::write(writer, attributes).map_err(invalid_data)?;
+
+    Ok((position, attributes_offset, attributes_offset + size))
+}
+
+// Write the footer, which starts at `position` in the file.
+pub(super) fn write_footer<W>(writer: &mut W, footer: &Footer, position: usize) -> Result<(), Error>
+where
+    W: Write,
+{
+    if let Err(e) = bincode::serialize_into(&mut *writer, footer) {
+        return Err(invalid_data(format!(
+            "Bincode could not serialize: {:?}",
+            e
+        )));
+    }
+
+    writer.write_all(&(position as u64).to_le_bytes())
+}
+
+/// Check if a file is a memory-mapped index.
+///
+/// # Parameters
+///
+///  * `name`:
+///      File to check.
+pub fn is_mapped(name: &str) -> Result<bool, Error> {
+    let mut magic = [0u8; 8];
+
+    match File::open(name)?.read_exact(&mut magic) {
+        Ok(()) => Ok(&magic == MAGIC),
+        Err(ref e) if e.kind() == ErrorKind::UnexpectedEof => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+// Check the magic number, the format version, and the length recorded
+// in the header, which only reads the first page of the file.
+fn check_header(mmap: &[u8], name: &str) -> Result<(), Error> {
+    if mmap.len() < HEADER + 8 || &mmap[0..8] != MAGIC {
+        return Err(invalid_data(format!(
+            "'{}' is not a memory-mapped index",
+            name
+        )));
+    }
+
+    let format_version = u64::from_le_bytes(*array_ref!(mmap, 8, 8));
+    if format_version != FORMAT_VERSION {
+        return Err(invalid_data(format!(
+            "Unsupported memory-mapped index version {}, expected {}",
+            format_version, FORMAT_VERSION
+        )));
+    }
+
+    let length = u64::from_le_bytes(*array_ref!(mmap, 16, 8));
+    if length != (mmap.len() - HEADER) as u64 {
+        return Err(invalid_data(format!(
+            "Truncated memory-mapped index: expected {} bytes, found {}",
+            length,
+            mmap.len() - HEADER
+        )));
+    }
+
+    Ok(())
+}
+
+/// Verify the integrity of a memory-mapped index.
+///
+/// This reads the whole file to compare its checksum with the one
+/// recorded when it was written, which [load](fn.load.html) does not
+/// do, so that loading stays cheap.
+///
+/// # Parameters
+///
+///  * `name`:
+///      File to verify, which contains a memory-mapped index.
+pub fn verify(name: &str) -> Result<(), Error> {
+    let file_in = File::open(name)?;
+    let mmap = unsafe { Mmap::map(&file_in)? };
+
+    check_header(&mmap, name)?;
+
+    let expected = u64::from_le_bytes(*array_ref!(mmap, 24, 8));
+    let computed = checksum(&mmap[HEADER..]);
+    if computed != expected {
+        return Err(invalid_data(format!(
+            "Corrupted memory-mapped index: checksum mismatch, expected {:#018x}, computed {:#018x}",
+            expected, computed
+        )));
+    }
+
+    Ok(())
+}
+
+/// Map an index in memory.
+///
+/// The positions and the property table are not copied, only the
+/// reference spaces are deserialized. The properties are deserialized
+/// when they are used for the first time.
+///
+/// Only the header of the file is checked, use
+/// [verify](fn.verify.html) to check the integrity of its content.
+///
+/// # Parameters
+///
+///  * `from`:
+///      File to map, which contains a memory-mapped index.
+pub fn load(from: &str) -> Result<(Vec<Space>, Core), Error> {
+    let file_in = File::open(from)?;
+
+    let mmap = Arc::new(unsafe { Mmap::map(&file_in)? });
+    check_header(&mmap, from)?;
+
+    let footer = u64::from_le_bytes(*array_ref!(mmap, mmap.len() - 8, 8)) as usize;
+    if footer < HEADER || footer > mmap.len() - 8 {
+        return Err(invalid_data(format!("Invalid footer offset {}", footer)));
+    }
+
+    let footer: Footer = match bincode::deserialize(&mmap[footer..mmap.len() - 8]) {
+        Ok(footer) => footer,
+        Err(e) => {
+            return Err(invalid_data(format!(
+                "Bincode could not deserialize: {:?}",
+                e
+            )))
+        }
+    };
+    debug!(
+        "Index {} written by version {}, format {}",
+        from, footer.crate_version, FORMAT_VERSION
+    );
+
+    let mut space_dbs = vec![];
+    for space_db in footer.space_db {
+        let mut resolutions = vec![];
+        for resolution in space_db.resolutions {
+            let index = MappedIndex::new(mmap.clone(), resolution.offset).map_err(invalid_data)?;
+
+            resolutions.push(SpaceIndex::mapped(
+                resolution.threshold_volume,
+                resolution.scale,
+                index,
+            ));
+        }
+
+        space_dbs.push(SpaceDB::from_resolutions(
+            &space_db.reference_space,
+            resolutions,
+        ));
+    }
+
+    let properties = MappedTable::new(mmap.clone(), footer.properties).map_err(invalid_data)?;
+    let attributes = MappedTable::new(mmap.clone(), footer.attributes).map_err(invalid_data)?;
+    if properties.len() != attributes.len() {
+        return Err(invalid_data(format!(
+            "Found {} attributes for {} properties",
+            attributes.len(),
+            properties.len()
+        )));
+    }
+
+    let core = Core::from_mapped(
+        footer.title,
+        footer.version,
+        properties,
+        attributes,
+        space_dbs,
+    );
+
+    Ok((footer.spaces, core))
+}
+
+/// Store an index as a memory-mapped index.
+///
+/// Identifiers removed from the dataset are dropped.
+///
+/// # Parameters
+///
+///  * `spaces`:
+///      Reference spaces used by the index.
+///
+///  * `core`:
+///      Dataset to store.
+///
+///  * `scales`, `max_elements`:
+///      Parameters used to build the index, see [build](fn.build.html).
+///
+///  * `to`:
+///      File to use to store the index.
+pub fn store(
+    spaces: &[Space],
+    core: &Core,
+    scales: Option<Vec<Vec<u32>>>,
+    max_elements: Option<usize>,
+    to: &str,
+) -> Result<(), Error> {
+    // Renumber the properties still registered, in identifier order.
+    let offsets = core.offsets();
+    let mut remap = vec![0; offsets.iter().max().map_or(0, |&m| m + 1)];
+    for (i, &offset) in offsets.iter().enumerate() {
+        remap[offset] = i;
+    }
+
+    let mut writer = BufWriter::new(File::create(to)?);
+    write_header(&mut writer)?;
+
+    let mut position = HEADER;
+    let mut space_db = vec![];
+
+    for s in core.space_db() {
+        let space = match spaces.iter().find(|space| space.name() == s.name()) {
+            Some(space) => space,
+            None => {
+                return Err(invalid_data(format!(
+                    "Reference space '{}' not provided",
+                    s.name()
+                )))
+            }
+        };
+
+        let mut resolutions = vec![];
+        for (r, index) in s.resolutions().iter().enumerate() {
+            let records = s
+                .objects(space, r)
+                .map_err(invalid_data)?
+                .into_iter()
+                .map(|(position, value)| (position, remap[value]))
+                .collect();
+
+            let size = MappedIndex::write(&mut writer, space.axes().len(), offsets.len(), records)
+                .map_err(invalid_data)?;
+
+            resolutions.push(Resolution {
+                threshold_volume: index.threshold(),
+                scale: index.scale().clone(),
+                offset: position,
+            });
+            position += size;
+        }
+
+        space_db.push(SpaceSection {
+            reference_space: s.name().clone(),
+            resolutions,
+        });
+    }
+
+    let keys = core.keys().map_err(invalid_data)?;
+    let attributes = keys
+        .iter()
+        .map(|p| Ok(core.attributes(p)?.cloned().unwrap_or_default()))
+        .collect::<Result<Vec<_>, crate::Error>>()
+        .map_err(invalid_data)?;
+    let (properties, attributes, position) =
+        write_tables(&mut writer, keys.iter(), attributes.iter(), position)?;
+
+    let footer = Footer {
+        title: core.name().clone(),
+        version: core.version().clone(),
+        crate_version: env!("CARGO_PKG_VERSION").to_string(),
+        scales,
+        max_elements,
+        spaces: spaces.to_vec(),
+        properties,
+        attributes,
+        space_db,
+    };
+
+    write_footer(&mut writer, &footer, position)?;
+    writer.flush()?;
+    drop(writer);
+
+    seal(to)
+}
+
+/// Build a memory-mapped index from a dataset.
+///
+/// The parameters are the same as for
+/// [bincode::build](../bincode/fn.build.html), and the index is
+/// stored under the same file name. [DataBase::load] detects the format
+/// of the index files.
+///
+/// [DataBase::load]: ../../struct.DataBase.html#method.load
+pub fn build(
+    name: &str,
+    version: &str,
+    scales: Option<Vec<Vec<u32>>>,
+    max_elements: Option<usize>,
+    threads: Option<usize>,
+) -> Result<(), Error> {
+    let fn_index = format!("{}.index", name);
+
+    let (spaces, core) = build_core(name, version, scales.clone(), max_elements, threads)?;
+
+    store(&spaces, &core, scales, max_elements, &fn_index)
+}
@@ -5,5 +5,11 @@
 
 pub mod bincode;
 pub mod json;
+pub mod mapped;
 pub mod model;
+pub mod nifti;
+pub mod streaming;
 pub mod xyz;
+
+#[cfg(test)]
+mod tests;
@@ -4,6 +4,8 @@
 //! process to exchange objects either through network or to storage.
 
 use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::convert::TryInto;
 
 use serde::Deserialize;
 use serde::Serialize;
@@ -11,7 +13,9 @@ use serde::Serialize;
 use crate::database;
 use database::space;
 use database::space_index::SpaceSetObject;
+use database::Attributes;
 use database::Core;
+use database::Error;
 
 /// Reference space definition.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -60,6 +64,92 @@ pub struct Graduation {
 /// This has a value per dimension of the space it is expressed in.
 pub type Point = Vec<f64>;
 
+// Center of the cell of the graduation of `space` at the encoded
+// `position`. The last cell of an axis starts on its maximum, which is
+// kept as its center.
+fn center(space: &space::Space, position: &space::Position) -> Result<Point, Error> {
+    let axes = space.axes();
+
+    Ok(space
+        .decode(position)?
+        .into_iter()
+        .enumerate()
+        .map(|(k, p)| {
+            let g = axes[k].graduation();
+            (p + g.epsilon / 2.0).min(g.maximum)
+        })
+        .collect())
+}
+
+// Encoded positions, at the graduation of `space`, covered by `shape`,
+// whose coordinates are expressed in `space`.
+//
+// Every cell containing a corner of a bounding box is covered. Other
+// shapes are encoded in `space`, and rasterised as they are queried, so
+// that an object is found by the shapes it was registered with. A simple shape
+// smaller than a cell still covers the cell containing the center of its
+// bounding box.
+fn rasterise(space: &space::Space, shape: &space::Shape) -> Result<Vec<space::Position>, Error> {
+    let axes = space.axes();
+
+    match shape {
+        space::Shape::BoundingBox(lower, higher) => {
+            let lower = space.encode(&Vec::<f64>::from(lower))?;
+            let higher = space.encode(&Vec::<f64>::from(higher))?;
+
+            // The higher corner is excluded by `Shape::rasterise`.
+            let (lower, higher): (Vec<_>, Vec<_>) = (0..lower.dimensions())
+                .map(|k| {
+                    let (l, h) = (lower[k].u64(), higher[k].u64());
+                    (l.min(h), l.max(h) + 1)
+                })
+                .unzip();
+
+            space::Shape::BoundingBox(lower.into(), higher.into()).rasterise()
+        }
+        space::Shape::HyperRectangle(_, vectors) | space::Shape::Ellipsoid(_, vectors)
+            if vectors.len() != axes.len() =>
+        {
+            Err(Error::InvalidDefinition(format!(
+                "Expected {} edges or semi-axes, found {}",
+                axes.len(),
+                vectors.len()
+            )))
+        }
+        shape => {
+            let shape = shape.encode(space)?;
+
+            // Drop the cells of the bounding box past the end of the axes.
+            let mut positions = shape
+                .rasterise_iter()?
+                .filter(|position| space.decode(position).is_ok())
+                .collect::<Vec<_>>();
+
+            let composite = matches!(
+                shape,
+                space::Shape::VoxelMask(_)
+                    | space::Shape::Union(_)
+                    | space::Shape::Intersection(_)
+                    | space::Shape::Difference(_, _)
+            );
+
+            if positions.is_empty() && !composite && !shape.is_mbb_empty() {
+                let (lower, higher) = shape.get_mbb();
+                let center = (0..lower.dimensions())
+                    .map(|k| ((lower[k].f64() + higher[k].f64()) / 2.0).max(0.0) as u64)
+                    .collect::<Vec<_>>()
+                    .into();
+
+                if space.decode(&center).is_ok() {
+                    positions.push(center);
+                }
+            }
+
+            Ok(positions)
+        }
+    }
+}
+
 pub mod v1 {
     //! REST API objects, v1.
 
@@ -70,6 +160,7 @@ pub mod v1 {
 
     use crate::database;
     use database::space;
+    use database::Error;
 
     use super::Point;
     use super::Properties;
@@ -99,6 +190,88 @@ pub mod v1 {
         pub vertices: Vec<Point>,
     }
 
+    impl Shape {
+        /// Encoded positions covered by the shape, at the graduation of
+        /// the reference space.
+        ///
+        /// The `vertices` are interpreted according to the type of the
+        /// shape:
+        ///  * `Point`: each vertex is a position,
+        ///  * `BoundingBox`: the lower and the higher corners,
+        ///  * `HyperSphere`: the center, followed by a vertex containing
+        ///    only the radius,
+        ///  * `HyperRectangle`: a corner, followed by one edge vector per
+        ///    dimension,
+        ///  * `Ellipsoid`: the center, followed by one semi-axis vector
+        ///    per dimension,
+        ///  * `Capsule`: the vertices of a polyline, followed by a vertex
+        ///    containing only the radius,
+        ///  * `Mesh`: a closed triangle mesh, each three consecutive
+        ///    vertices form a face.
+        ///
+        /// # Parameters
+        ///
+        ///  * `space`:
+        ///      Reference space of the shape.
+        pub fn rasterise(&self, space: &space::Space) -> Result<Vec<space::Position>, Error> {
+            let shapes = match (self.type_name.as_str(), self.vertices.as_slice()) {
+                ("Point", vertices) if !vertices.is_empty() => vertices
+                    .iter()
+                    .map(|vertex| space::Shape::Point(vertex.into()))
+                    .collect(),
+                ("BoundingBox", [lower, higher]) => {
+                    vec![space::Shape::BoundingBox(lower.into(), higher.into())]
+                }
+                ("HyperSphere", [center, radius]) if radius.len() == 1 => {
+                    vec![space::Shape::HyperSphere(center.into(), radius[0].into())]
+                }
+                ("HyperRectangle", [corner, edges @ ..]) if !edges.is_empty() => {
+                    vec![space::Shape::HyperRectangle(
+                        corner.into(),
+                        edges.iter().map(|edge| edge.into()).collect(),
+                    )]
+                }
+                ("Capsule", [vertices @ .., radius])
+                    if !vertices.is_empty() && radius.len() == 1 =>
+                {
+                    vec![space::Shape::Capsule(
+                        vertices.iter().map(|vertex| vertex.into()).collect(),
+                        radius[0].into(),
+                    )]
+                }
+                ("Ellipsoid", [center, semi_axes @ ..]) if !semi_axes.is_empty() => {
+                    vec![space::Shape::Ellipsoid(
+                        center.into(),
+                        semi_axes.iter().map(|semi_axis| semi_axis.into()).collect(),
+                    )]
+                }
+                ("Mesh", vertices) if !vertices.is_empty() && vertices.len() % 3 == 0 => {
+                    let faces = (0..vertices.len() / 3)
+                        .map(|f| [3 * f, 3 * f + 1, 3 * f + 2])
+                        .collect();
+                    vec![space::Shape::Mesh(
+                        vertices.iter().map(|vertex| vertex.into()).collect(),
+                        faces,
+                    )]
+                }
+                _ => {
+                    return Err(Error::InvalidDefinition(format!(
+                        "Unsupported shape '{}' with {} vertices",
+                        self.type_name,
+                        self.vertices.len()
+                    )))
+                }
+            };
+
+            let mut positions = vec![];
+            for shape in &shapes {
+                positions.append(&mut super::rasterise(space, shape)?);
+            }
+
+            Ok(positions)
+        }
+    }
+
     /// Convert a list of properties grouped by space id, then positions to a
     /// list of Spatial Objects for the rest API v1.
     ///
@@ -152,7 +325,11 @@ pub mod v2 {
 
     use crate::database;
     use database::space;
+    use database::Core;
+    use database::DataBase;
+    use database::Error;
 
+    use super::v1;
     use super::Point;
     use super::Properties;
 
@@ -206,6 +383,233 @@ pub mod v2 {
 
         /// List of hyperspheres, stored as (`center`, radius) tuples.
         HyperSpheres(Vec<(Point, f64)>),
+
+        /// List of hyperrectangles of any orientation, stored as
+        /// (`corner`, `edges`) tuples, with one edge vector per
+        /// dimension, starting from `corner`.
+        HyperRectangles(Vec<(Point, Vec<Point>)>),
+
+        /// List of ellipsoids of any orientation, stored as (`center`,
+        /// `semi-axes`) tuples, with one semi-axis vector per dimension.
+        Ellipsoids(Vec<(Point, Vec<Point>)>),
+
+        /// List of spheres swept along polylines, stored as (`vertices`,
+        /// radius) tuples.
+        Capsules(Vec<(Vec<Point>, f64)>),
+
+        /// List of closed triangle meshes, stored as (`vertices`,
+        /// `faces`) tuples, where each face is a triplet of offsets into
+        /// `vertices`.
+        Meshes(Vec<(Vec<Point>, Vec<[usize; 3]>)>),
+    }
+
+    impl From<&Shape> for Vec<space::Shape> {
+        fn from(shape: &Shape) -> Self {
+            match shape {
+                Shape::Points(points) => points
+                    .iter()
+                    .map(|point| space::Shape::Point(point.into()))
+                    .collect(),
+                Shape::BoundingBoxes(boxes) => boxes
+                    .iter()
+                    .map(|(lower, higher)| space::Shape::BoundingBox(lower.into(), higher.into()))
+                    .collect(),
+                Shape::HyperSpheres(spheres) => spheres
+                    .iter()
+                    .map(|(center, radius)| {
+                        space::Shape::HyperSphere(center.into(), (*radius).into())
+                    })
+                    .collect(),
+                Shape::HyperRectangles(rectangles) => rectangles
+                    .iter()
+                    .map(|(corner, edges)| {
+                        space::Shape::HyperRectangle(
+                            corner.into(),
+                            edges.iter().map(|edge| edge.into()).collect(),
+                        )
+                    })
+                    .collect(),
+                Shape::Ellipsoids(ellipsoids) => ellipsoids
+                    .iter()
+                    .map(|(center, semi_axes)| {
+                        space::Shape::Ellipsoid(
+                            center.into(),
+                            semi_axes.iter().map(|semi_axis| semi_axis.into()).collect(),
+                        )
+                    })
+                    .collect(),
+                Shape::Capsules(capsules) => capsules
+                    .iter()
+                    .map(|(vertices, radius)| {
+                        space::Shape::Capsule(
+                            vertices.iter().map(|vertex| vertex.into()).collect(),
+                            (*radius).into(),
+                        )
+                    })
+                    .collect(),
+                Shape::Meshes(meshes) => meshes
+                    .iter()
+                    .map(|(vertices, faces)| {
+                        space::Shape::Mesh(
+                            vertices.iter().map(|vertex| vertex.into()).collect(),
+                            faces.clone(),
+                        )
+                    })
+                    .collect(),
+            }
+        }
+    }
+
+    impl From<&space::Shape> for Shape {
+        fn from(shape: &space::Shape) -> Self {
+            match shape {
+                space::Shape::Point(position) => Shape::Points(vec![position.into()]),
+                space::Shape::BoundingBox(lower, higher) => {
+                    Shape::BoundingBoxes(vec![(lower.into(), higher.into())])
+                }
+                space::Shape::HyperSphere(center, radius) => {
+                    Shape::HyperSpheres(vec![(center.into(), radius.f64())])
+                }
+                space::Shape::HyperRectangle(corner, edges) => Shape::HyperRectangles(vec![(
+                    corner.into(),
+                    edges.iter().map(|edge| edge.into()).collect(),
+                )]),
+                space::Shape::Capsule(vertices, radius) => Shape::Capsules(vec![(
+                    vertices.iter().map(|vertex| vertex.into()).collect(),
+                    radius.f64(),
+                )]),
+                space::Shape::Ellipsoid(center, semi_axes) => Shape::Ellipsoids(vec![(
+                    center.into(),
+                    semi_axes.iter().map(|semi_axis| semi_axis.into()).collect(),
+                )]),
+                space::Shape::Mesh(vertices, faces) => Shape::Meshes(vec![(
+                    vertices.iter().map(|vertex| vertex.into()).collect(),
+                    faces.clone(),
+                )]),
+                // Voxel masks are described by their selected voxels.
+                space::Shape::VoxelMask(mask) => {
+                    Shape::Points(mask.centers().iter().map(|center| center.into()).collect())
+                }
+                // Composite shapes are approximated by their bounding box.
+                space::Shape::Union(_)
+                | space::Shape::Intersection(_)
+                | space::Shape::Difference(_, _) => {
+                    if shape.is_mbb_empty() {
+                        return Shape::Points(vec![]);
+                    }
+
+                    let (lower, higher) = shape.get_mbb();
+                    Shape::BoundingBoxes(vec![((&lower).into(), (&higher).into())])
+                }
+            }
+        }
+    }
+
+    impl From<&Volume> for Vec<v1::Shape> {
+        fn from(volume: &Volume) -> Self {
+            let shape = |type_name: &str, vertices| v1::Shape {
+                type_name: type_name.to_string(),
+                reference_space: volume.space.clone(),
+                vertices,
+            };
+
+            volume
+                .shapes
+                .iter()
+                .flat_map(|shapes| match shapes {
+                    Shape::Points(points) => vec![shape("Point", points.clone())],
+                    Shape::BoundingBoxes(boxes) => boxes
+                        .iter()
+                        .map(|(lower, higher)| {
+                            shape("BoundingBox", vec![lower.clone(), higher.clone()])
+                        })
+                        .collect(),
+                    Shape::HyperSpheres(spheres) => spheres
+                        .iter()
+                        .map(|(center, radius)| {
+                            shape("HyperSphere", vec![center.clone(), vec![*radius]])
+                        })
+                        .collect(),
+                    Shape::HyperRectangles(rectangles) => rectangles
+                        .iter()
+                        .map(|(corner, edges)| {
+                            let mut vertices = vec![corner.clone()];
+                            vertices.extend(edges.iter().cloned());
+                            shape("HyperRectangle", vertices)
+                        })
+                        .collect(),
+                    Shape::Ellipsoids(ellipsoids) => ellipsoids
+                        .iter()
+                        .map(|(center, semi_axes)| {
+                            let mut vertices = vec![center.clone()];
+                            vertices.extend(semi_axes.iter().cloned());
+                            shape("Ellipsoid", vertices)
+                        })
+                        .collect(),
+                    Shape::Capsules(capsules) => capsules
+                        .iter()
+                        .map(|(vertices, radius)| {
+                            let mut vertices = vertices.clone();
+                            vertices.push(vec![*radius]);
+                            shape("Capsule", vertices)
+                        })
+                        .collect(),
+                    // Faces referring to missing vertices are rejected
+                    // when rasterising.
+                    Shape::Meshes(meshes) => meshes
+                        .iter()
+                        .map(|(vertices, faces)| {
+                            let soup = faces
+                                .iter()
+                                .flatten()
+                                .map(|&v| vertices.get(v).cloned().unwrap_or_default())
+                                .collect();
+                            shape("Mesh", soup)
+                        })
+                        .collect(),
+                })
+                .collect()
+        }
+    }
+
+    impl From<&SpatialObject> for v1::SpatialObject {
+        fn from(object: &SpatialObject) -> Self {
+            v1::SpatialObject {
+                properties: object.properties.clone(),
+                shapes: object
+                    .volumes
+                    .iter()
+                    .flat_map(Vec::<v1::Shape>::from)
+                    .collect(),
+            }
+        }
+    }
+
+    /// Generate an index from objects of the rest API v2.
+    ///
+    /// The parameters are the same as for
+    /// [build_index](../fn.build_index.html), except for `objects`,
+    /// whose volumes are indexed at every position they cover.
+    pub fn build_index(
+        name: &str,
+        version: &str,
+        spaces: &[space::Space],
+        objects: &[SpatialObject],
+        scales: Option<Vec<Vec<u32>>>,
+        max_elements: Option<usize>,
+        threads: Option<usize>,
+    ) -> Result<Core, Error> {
+        let objects = objects.iter().map(|o| o.into()).collect::<Vec<_>>();
+
+        super::build_index(
+            name,
+            version,
+            spaces,
+            &objects,
+            scales,
+            max_elements,
+            threads,
+        )
     }
 
     /// Convert a list of properties grouped by space id, then positions to a
@@ -249,6 +653,164 @@ pub mod v2 {
 
         results
     }
+
+    // Merge encoded cells into bounding boxes, given as (`lower`,
+    // `higher`) corners included, which cover exactly the same cells.
+    fn merge(mut cells: Vec<Vec<u64>>) -> Vec<(Vec<u64>, Vec<u64>)> {
+        cells.sort_unstable();
+        cells.dedup();
+
+        let dimensions = cells.first().map_or(0, |cell| cell.len());
+        let mut boxes = cells
+            .into_iter()
+            .map(|cell| (cell.clone(), cell))
+            .collect::<Vec<_>>();
+
+        // Extend the boxes along one axis at a time, starting with the
+        // last one, which follows the order of the cells.
+        for k in (0..dimensions).rev() {
+            let others = (0..dimensions).filter(|&i| i != k).collect::<Vec<_>>();
+
+            // Boxes which only differ along `k` are next to each other
+            // once sorted.
+            boxes.sort_by_cached_key(|(lower, higher)| {
+                let mut key = Vec::with_capacity(2 * dimensions);
+                for &i in &others {
+                    key.push(lower[i]);
+                    key.push(higher[i]);
+                }
+                key.push(lower[k]);
+                key
+            });
+
+            let mut merged: Vec<(Vec<u64>, Vec<u64>)> = Vec::with_capacity(boxes.len());
+            for (lower, higher) in boxes {
+                match merged.last_mut() {
+                    Some((l, h))
+                        if h[k] + 1 == lower[k]
+                            && others
+                                .iter()
+                                .all(|&i| l[i] == lower[i] && h[i] == higher[i]) =>
+                    {
+                        h[k] = higher[k]
+                    }
+                    _ => merged.push((lower, higher)),
+                }
+            }
+            boxes = merged;
+        }
+
+        boxes
+    }
+
+    /// Convert a list of properties grouped by space id, then positions to a
+    /// list of Spatial Objects for the rest API v2, merging the positions
+    /// into bounding boxes.
+    ///
+    /// The positions of a volume are encoded in its reference space, and
+    /// adjacent cells are merged into the largest bounding boxes possible.
+    /// Each box is given by the centers of its lowest and highest cells,
+    /// and covers exactly the positions it replaces, once rasterised at
+    /// the same graduation.
+    ///
+    /// Positions converted to another output space are not aligned on the
+    /// cells of the space they are listed under, so they are returned as
+    /// points, as by [to_spatial_objects](fn.to_spatial_objects.html).
+    ///
+    /// # Parameters
+    ///
+    ///  * `db`:
+    ///      Database in which the reference spaces are registered.
+    ///
+    ///  * `output_space`:
+    ///      Output space requested in the query which produced `list`.
+    ///
+    ///  * `list`:
+    ///      A list of (**Space Id**, [ ( *Spatial position*, `&Properties` ) ]) tuples.
+    pub fn to_spatial_objects_compact(
+        db: &DataBase,
+        output_space: Option<&str>,
+        list: Vec<(&String, Vec<(space::Position, &database::Properties)>)>,
+    ) -> Result<Vec<SpatialObject>, Error> {
+        // Filter per Properties, in order to regroup by it, then build a single SpatialObject per Properties.
+        let mut hashmap = HashMap::new();
+        for (space_id, v) in list {
+            if output_space.is_some() && output_space != Some(space_id.as_str()) {
+                for (position, properties) in v {
+                    hashmap
+                        .entry(properties)
+                        .or_insert_with(HashMap::new)
+                        .entry(space_id)
+                        .or_insert_with(|| (vec![], vec![]))
+                        .1
+                        .push(position.into());
+                }
+                continue;
+            }
+
+            let space = db.space(space_id)?;
+            let axes = space.axes();
+
+            for (position, properties) in v {
+                // Positions are decoded from the lower corner of their
+                // cell, use the center of the cell to encode them back.
+                let center = Vec::<f64>::from(&position)
+                    .iter()
+                    .zip(axes)
+                    .map(|(p, axis)| {
+                        let g = axis.graduation();
+                        (p + g.epsilon / 2.0).min(g.maximum)
+                    })
+                    .collect::<Vec<_>>();
+                let cell = space.encode(&center)?;
+
+                hashmap
+                    .entry(properties)
+                    .or_insert_with(HashMap::new)
+                    .entry(space_id)
+                    .or_insert_with(|| (vec![], vec![]))
+                    .0
+                    .push((0..cell.dimensions()).map(|k| cell[k].u64()).collect());
+            }
+        }
+
+        let mut results = vec![];
+        for (properties, v) in hashmap.iter_mut() {
+            let mut volumes = vec![];
+            for (space_id, (cells, points)) in v.drain() {
+                let mut shapes = vec![];
+
+                if !cells.is_empty() {
+                    let space = db.space(space_id)?;
+
+                    let mut boxes = vec![];
+                    for (lower, higher) in merge(cells) {
+                        boxes.push((
+                            super::center(&space, &lower.into())?,
+                            super::center(&space, &higher.into())?,
+                        ));
+                    }
+                    shapes.push(Shape::BoundingBoxes(boxes));
+                }
+
+                if !points.is_empty() {
+                    shapes.push(Shape::Points(points));
+                }
+
+                volumes.push(Volume {
+                    space: space_id.clone(),
+                    shapes,
+                });
+            }
+
+            results.push(SpatialObject {
+                properties: properties.into(),
+                volumes,
+            });
+        }
+
+        Ok(results)
+    }
 }
 
 /// **Properties** which are registered at one or more spatial locations.
@@ -261,6 +823,11 @@ pub struct Properties {
 
     /// An arbitrary string.
     pub id: String,
+
+    /// Typed attributes of the *identifier*, such as the species or the
+    /// modality.
+    #[serde(default)]
+    pub attributes: Attributes,
 }
 
 impl From<&space::Graduation> for Graduation {
@@ -274,19 +841,20 @@ impl From<&space::Graduation> for Graduation {
     }
 }
 
-impl From<Axis> for space::Axis {
-    fn from(axis: Axis) -> Self {
+impl TryFrom<Axis> for space::Axis {
+    type Error = Error;
+
+    fn try_from(axis: Axis) -> Result<Self, Self::Error> {
         let g = axis.graduation;
 
         space::Axis::new(
             &axis.measurement_unit,
             axis.unit_vector,
-            g.set.as_str().into(),
+            g.set.as_str().try_into()?,
             g.minimum,
             g.maximum,
             g.steps,
         )
-        .unwrap_or_else(|e| panic!("Unable to create Axis as defined: {}", e))
     }
 }
 
@@ -300,17 +868,19 @@ impl From<&space::Axis> for Axis {
     }
 }
 
-impl From<&Space> for space::Space {
-    fn from(space: &Space) -> Self {
+impl TryFrom<&Space> for space::Space {
+    type Error = Error;
+
+    fn try_from(space: &Space) -> Result<Self, Self::Error> {
         let axes = space
             .axes
             .iter()
-            .map(|a| a.clone().into())
-            .collect::<Vec<_>>();
+            .map(|a| a.clone().try_into())
+            .collect::<Result<Vec<_>, _>>()?;
 
         let system = space::CoordinateSystem::new(space.origin.clone(), axes);
 
-        space::Space::new(&space.name, system)
+        Ok(space::Space::new(&space.name, system))
     }
 }
 
@@ -326,15 +896,41 @@ impl From<&space::Space> for Space {
     }
 }
 
+impl Properties {
+    /// Copy the attributes registered for this *identifier* in `core`.
+    ///
+    /// Query results only reference the *identifiers*, use this to add
+    /// their attributes to the converted results.
+    ///
+    /// # Parameters
+    ///
+    ///  * `core`:
+    ///      Dataset in which the *identifier* is registered.
+    pub fn load_attributes(&mut self, core: &Core) -> Result<(), Error> {
+        if let Some(attributes) = core.attributes(&(&*self).into())? {
+            self.attributes = attributes.clone();
+        }
+
+        Ok(())
+    }
+}
+
 impl From<&&database::Properties> for Properties {
     fn from(p: &&database::Properties) -> Self {
         Properties {
             type_name: p.type_name().to_string(),
             id: p.id().into(),
+            attributes: Attributes::new(),
         }
     }
 }
 
+impl From<&Properties> for database::Properties {
+    fn from(p: &Properties) -> Self {
+        database::Properties::new(p.id.as_str(), p.type_name.as_str())
+    }
+}
+
 pub use v1::SpatialObject;
 
 /// Generate an index.
@@ -352,7 +948,9 @@ pub use v1::SpatialObject;
 ///     space is known will be indexed.
 ///
 /// * `objects`:
-///     The data points to index.
+///     The data points to index. Volumes are indexed at every position
+///     they cover, see
+///     [v1::Shape::rasterise](v1/struct.Shape.html#method.rasterise).
 ///
 /// * `scales`:
 ///     An optional list of specific index resolutions to generates on
@@ -365,6 +963,11 @@ pub use v1::SpatialObject;
 ///     equal to the number of distinct Ids, or smaller or equal to this
 ///     value.
 ///
+/// * `threads`:
+///     The number of threads to use to build the index, or the available
+///     parallelism when `None`. The index is the same whatever the
+///     number of threads.
+///
 /// **Note**: `max_elements` is ignored when `scales` is not `None`.
 pub fn build_index(
     name: &str,
@@ -373,53 +976,87 @@ pub fn build_index(
     objects: &[SpatialObject],
     scales: Option<Vec<Vec<u32>>>,
     max_elements: Option<usize>,
-) -> Result<Core, String> {
+    threads: Option<usize>,
+) -> Result<Core, Error> {
     let mut properties = vec![];
+    let mut attributes = HashMap::new();
     let mut space_set_objects = vec![];
     {
         let mut properties_ref = vec![];
         let mut properties_hm = HashMap::new();
 
         for object in objects {
-            let value = match properties_hm.get(object.properties.id.as_str()) {
+            // Objects are identified by their id and their type.
+            let key = (
+                object.properties.id.as_str(),
+                object.properties.type_name.as_str(),
+            );
+
+            if !object.properties.attributes.is_empty() {
+                attributes
+                    .entry(key)
+                    .or_insert_with(Attributes::new)
+                    .extend(object.properties.attributes.clone());
+            }
+
+            let value = match properties_hm.get(&key) {
                 Some(_) => {
-                    properties_ref.push(object.properties.id.as_str());
+                    properties_ref.push(key);
                     properties_ref.len() - 1
                 }
                 None => {
-                    properties_hm.insert(
-                        object.properties.id.as_str(),
-                        database::Properties::Feature(object.properties.id.clone()),
-                    );
+                    properties_hm.insert(key, database::Properties::from(&object.properties));
 
-                    properties_ref.push(object.properties.id.as_str());
+                    properties_ref.push(key);
                     properties_ref.len() - 1
                 }
             };
 
-            for point in &object.shapes {
-                assert_eq!(point.type_name, "Point");
+            // Overlapping shapes cover some positions more than once.
+            let mut cells = vec![];
+            for shape in &object.shapes {
+                let s = match spaces
+                    .iter()
+                    .position(|s| s.name() == &shape.reference_space)
+                {
+                    None => continue,
+                    Some(s) => s,
+                };
+
+                for position in shape.rasterise(&spaces[s])? {
+                    let cell = (0..position.dimensions())
+                        .map(|k| position[k].u64())
+                        .collect::<Vec<_>>();
+                    cells.push((s, cell));
+                }
+            }
+            cells.sort_unstable();
+            cells.dedup();
 
+            // The positions are encoded again when building the index,
+            // use the center of the cells to be exact.
+            for (s, cell) in cells {
                 space_set_objects.push(SpaceSetObject::new(
-                    &point.reference_space,
-                    // Use a reference to prevent an allocation
-                    (&point.vertices[0]).into(),
+                    spaces[s].name(),
+                    center(&spaces[s], &cell.into())?.into(),
                     value,
                 ))
             }
         }
 
         properties.append(&mut properties_hm.drain().map(|(_, v)| v).collect::<Vec<_>>());
-        properties.sort_unstable_by(|a, b| a.id().cmp(b.id()));
+        properties.sort_unstable_by(|a, b| (a.id(), a.type_name()).cmp(&(b.id(), b.type_name())));
 
         space_set_objects.iter_mut().for_each(|object| {
-            let id = properties_ref[object.value()];
-            let value = properties.binary_search_by_key(&id, |p| p.id()).unwrap();
+            let key = properties_ref[object.value()];
+            let value = properties
+                .binary_search_by_key(&key, |p| (p.id(), p.type_name()))
+                .unwrap();
             object.set_value(value);
         });
     }
 
-    Core::new(
+    let mut core = Core::new(
         name,
         version,
         spaces,
@@ -427,5 +1064,12 @@ pub fn build_index(
         space_set_objects,
         scales,
         max_elements,
-    )
+        threads,
+    )?;
+
+    for ((id, type_name), attributes) in attributes {
+        core.set_attributes(&database::Properties::new(id, type_name), attributes)?;
+    }
+
+    Ok(core)
 }
@@ -0,0 +1,374 @@
+//! # NIfTI-1 file format
+//!
+//! This module supports reading the label volumes distributed with
+//! brain atlases, in the [NIfTI-1] format.
+//!
+//! Only single files (`.nii`) are supported, uncompressed. Images with
+//! more than three dimensions are read up to their first volume.
+//!
+//! # Coordinate system
+//!
+//! The position of the center of each voxel is computed from the
+//! transformation stored in the header, as per the specification:
+//!  * the `sform` affine transformation, if its code is set, otherwise
+//!  * the `qform` quaternion, scaling and offset, if its code is set,
+//!    otherwise
+//!  * the voxel sizes only.
+//!
+//! The positions are expected to be expressed in the reference space
+//! used for the index.
+//!
+//! [NIfTI-1]: https://nifti.nimh.nih.gov/nifti-1
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Error;
+use std::io::ErrorKind;
+
+use memmap::Mmap;
+
+use super::bincode::store;
+use super::model::v1::Shape;
+use super::model::v1::SpatialObject;
+use super::model::Properties;
+use crate::space::VoxelMask;
+
+// Size of the NIfTI-1 header.
+const HEADER: usize = 348;
+
+// Voxels of a single volume.
+struct Image {
+    dimensions: [usize; 3],
+    affine: [[f64; 4]; 3],
+    values: Vec<f64>,
+}
+
+impl Image {
+    // Position of the center of the voxel at `offset` in `values`.
+    fn position(&self, offset: usize) -> Vec<f64> {
+        let [ni, nj, _] = self.dimensions;
+        let indices = [
+            (offset % ni) as f64,
+            ((offset / ni) % nj) as f64,
+            (offset / (ni * nj)) as f64,
+        ];
+
+        self.affine
+            .iter()
+            .map(|row| row[0] * indices[0] + row[1] * indices[1] + row[2] * indices[2] + row[3])
+            .collect()
+    }
+}
+
+fn invalid_data<S>(reason: S) -> Error
+where
+    S: Into<String>,
+{
+    Error::new(ErrorKind::InvalidData, reason.into())
+}
+
+fn read(from: &str) -> Result<Image, Error> {
+    let file_in = File::open(from)?;
+    let data = unsafe { Mmap::map(&file_in)? };
+
+    if data.len() < HEADER {
+        return Err(invalid_data(format!("'{}' is not a NIfTI-1 file", from)));
+    }
+
+    // The size of the header tells the byte order.
+    let little_endian = match (
+        i32::from_le_bytes(*array_ref!(data, 0, 4)),
+        i32::from_be_bytes(*array_ref!(data, 0, 4)),
+    ) {
+        (348, _) => true,
+        (_, 348) => false,
+        _ => return Err(invalid_data(format!("'{}' is not a NIfTI-1 file", from))),
+    };
+
+    if &data[344..348] != b"n+1\0" {
+        return Err(invalid_data(format!(
+            "'{}' is not a single file NIfTI-1 image",
+            from
+        )));
+    }
+
+    let i16_at = |offset: usize| {
+        let bytes = *array_ref!(data, offset, 2);
+        if little_endian {
+            i16::from_le_bytes(bytes)
+        } else {
+            i16::from_be_bytes(bytes)
+        }
+    };
+    let f32_at = |offset: usize| {
+        let bytes = *array_ref!(data, offset, 4);
+        f64::from(if little_endian {
+            f32::from_le_bytes(bytes)
+        } else {
+            f32::from_be_bytes(bytes)
+        })
+    };
+
+    let rank = i16_at(40);
+    if !(1..=7).contains(&rank) {
+        return Err(invalid_data(format!(
+            "Invalid number of dimensions {}",
+            rank
+        )));
+    }
+
+    let mut dimensions = [1; 3];
+    for (k, dimension) in dimensions.iter_mut().enumerate().take(rank as usize) {
+        let d = i16_at(42 + 2 * k);
+        if d < 1 {
+            return Err(invalid_data(format!("Invalid dimension {}: {}", k, d)));
+        }
+        *dimension = d as usize;
+    }
+
+    let datatype = i16_at(70);
+    let size = match datatype {
+        2 | 256 => 1,
+        4 | 512 => 2,
+        8 | 16 | 768 => 4,
+        64 | 1024 | 1280 => 8,
+        _ => return Err(invalid_data(format!("Unsupported datatype {}", datatype))),
+    };
+
+    let count = dimensions.iter().product::<usize>();
+    let offset = f32_at(108) as usize;
+    if offset < HEADER || data.len() < offset + count * size {
+        return Err(invalid_data(format!(
+            "Truncated image: expected {} bytes, found {}",
+            offset.max(HEADER) + count * size,
+            data.len()
+        )));
+    }
+
+    // A slope of zero means the values are not scaled.
+    let (slope, intercept) = match (f32_at(112), f32_at(116)) {
+        (slope, intercept) if slope != 0.0 && slope.is_finite() => (slope, intercept),
+        _ => (1.0, 0.0),
+    };
+
+    let mut values = Vec::with_capacity(count);
+    for v in 0..count {
+        let bytes = &data[offset + v * size..offset + (v + 1) * size];
+        macro_rules! value {
+            ($t:ty, $n:expr) => {{
+                let bytes = *array_ref!(bytes, 0, $n);
+                if little_endian {
+                    <$t>::from_le_bytes(bytes)
+                } else {
+                    <$t>::from_be_bytes(bytes)
+                }
+            }};
+        }
+
+        let value = match datatype {
+            2 => f64::from(bytes[0]),
+            256 => f64::from(bytes[0] as i8),
+            4 => f64::from(value!(i16, 2)),
+            512 => f64::from(value!(u16, 2)),
+            8 => f64::from(value!(i32, 4)),
+            768 => f64::from(value!(u32, 4)),
+            16 => f64::from(value!(f32, 4)),
+            64 => value!(f64, 8),
+            1024 => value!(i64, 8) as f64,
+            1280 => value!(u64, 8) as f64,
+            _ => unreachable!(),
+        };
+
+        values.push(value * slope + intercept);
+    }
+
+    let pixdim = |k: usize| f32_at(76 + 4 * k);
+
+    let affine = if i16_at(254) > 0 {
+        // sform
+        let row = |r: usize| {
+            let base = 280 + 16 * r;
+            [
+                f32_at(base),
+                f32_at(base + 4),
+                f32_at(base + 8),
+                f32_at(base + 12),
+            ]
+        };
+        [row(0), row(1), row(2)]
+    } else if i16_at(252) > 0 {
+        // qform
+        let (b, c, d) = (f32_at(256), f32_at(260), f32_at(264));
+        let a = (1.0 - (b * b + c * c + d * d)).max(0.0).sqrt();
+        let qfac = if pixdim(0) < 0.0 { -1.0 } else { 1.0 };
+        let scale = [pixdim(1), pixdim(2), qfac * pixdim(3)];
+
+        let rotation = [
+            [
+                a * a + b * b - c * c - d * d,
+                2.0 * (b * c - a * d),
+                2.0 * (b * d + a * c),
+            ],
+            [
+                2.0 * (b * c + a * d),
+                a * a + c * c - b * b - d * d,
+                2.0 * (c * d - a * b),
+            ],
+            [
+                2.0 * (b * d - a * c),
+                2.0 * (c * d + a * b),
+                a * a + d * d - b * b - c * c,
+            ],
+        ];
+        let offset = [f32_at(268), f32_at(272), f32_at(276)];
+
+        let mut affine = [[0.0; 4]; 3];
+        for r in 0..3 {
+            for c in 0..3 {
+                affine[r][c] = rotation[r][c] * scale[c];
+            }
+            affine[r][3] = offset[r];
+        }
+        affine
+    } else {
+        [
+            [pixdim(1), 0.0, 0.0, 0.0],
+            [0.0, pixdim(2), 0.0, 0.0],
+            [0.0, 0.0, pixdim(3), 0.0],
+        ]
+    };
+
+    Ok(Image {
+        dimensions,
+        affine,
+        values,
+    })
+}
+
+fn convert(
+    image: &Image,
+    reference_space: &str,
+    type_name: &str,
+) -> Result<Vec<SpatialObject>, Error> {
+    // Regroup the voxels per label, zero being the background.
+    let mut labels = HashMap::new();
+    for (offset, &value) in image.values.iter().enumerate() {
+        if value != 0.0 {
+            // Labels are integers, even when stored as floating point
+            // values, and are used as identifiers.
+            if value.fract() != 0.0 || value.abs() >= 2f64.powi(63) {
+                return Err(invalid_data(format!(
+                    "Voxel {} has value {}, which is not an integer label",
+                    offset, value
+                )));
+            }
+
+            labels
+                .entry(value as i64)
+                .or_insert_with(Vec::new)
+                .push(image.position(offset));
+        }
+    }
+
+    let mut labels = labels.drain().collect::<Vec<_>>();
+    labels.sort_unstable_by_key(|(label, _)| *label);
+
+    Ok(labels
+        .into_iter()
+        .map(|(label, vertices)| {
+            let properties = Properties {
+                type_name: type_name.to_string(),
+                id: label.to_string(),
+                attributes: Default::default(),
+            };
+
+            let shapes = vec![Shape {
+                type_name: "Point".to_string(),
+                reference_space: reference_space.to_string(),
+                vertices,
+            }];
+
+            SpatialObject { properties, shapes }
+        })
+        .collect())
+}
+
+/// Read a NIfTI-1 label volume, and convert each label into a spatial
+/// object.
+///
+/// Voxels with a value of zero are considered as background and are
+/// skipped. Every other value is a label, whose *identifier* is the
+/// value, and which is located at the centers of its voxels. Labels
+/// have to be integers.
+///
+/// # Parameters
+///
+///  * `from`:
+///      File to read.
+///
+///  * `reference_space`:
+///      Name of the reference space in which the positions of the
+///      voxels are expressed.
+///
+///  * `type_name`:
+///      *Kind* of the objects.
+pub fn load(
+    from: &str,
+    reference_space: &str,
+    type_name: &str,
+) -> Result<Vec<SpatialObject>, Error> {
+    convert(&read(from)?, reference_space, type_name)
+}
+
+/// Read a NIfTI-1 label volume as a voxel mask.
+///
+/// The mask can be used as a query shape, once encoded in its
+/// reference space.
+///
+/// # Parameters
+///
+///  * `from`:
+///      File to read.
+///
+///  * `label`:
+///      Value of the voxels to select, or all the voxels with a value
+///      different from zero if `None`.
+pub fn load_mask(from: &str, label: Option<f64>) -> Result<VoxelMask, Error> {
+    let image = read(from)?;
+
+    let voxels = image
+        .values
+        .iter()
+        .map(|&value| match label {
+            None => value != 0.0,
+            Some(label) => value == label,
+        })
+        .collect();
+
+    VoxelMask::new(image.dimensions, image.affine, voxels).map_err(|e| invalid_data(e.to_string()))
+}
+
+/// Read a NIfTI-1 label volume and convert it to the internal format
+/// for indexing.
+///
+/// This only converts the data point definitions, a reference space
+/// needs to be provided as well to be able to build an index.
+///
+///  # Parameters
+///
+///  * `name`:
+///      Base name of the file,
+///       * `.nii` will be automatically appended for the source file, while
+///       * `.bin` will be appended for the output file.
+///
+///  * `reference_space`:
+///      Name of the reference space in which the positions of the
+///      voxels are expressed.
+pub fn from(name: &str, reference_space: &str) -> Result<(), Error> {
+    let fn_in = format!("{}.nii", name);
+    let fn_out = format!("{}.bin", name);
+
+    let v = load(&fn_in, reference_space, "Feature")?;
+
+    store(v, &fn_out)
+}
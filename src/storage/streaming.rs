@@ -0,0 +1,671 @@
+//! Streaming index builder
+//!
+//! Builds memory-mapped indices from datasets larger than the available
+//! memory. The objects are read one at a time, their positions sorted
+//! in bounded runs spilled to disk, then merged into the sections of
+//! the index.
+//!
+//! Only the properties, one per *identifier*, are kept in memory.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::fs;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::Error;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+
+use super::bincode;
+use super::json;
+use super::mapped;
+use super::mapped::invalid_data;
+use super::model;
+use crate::database::mapped_index::coordinates;
+use crate::database::mapped_index::morton_cmp;
+use crate::database::mapped_index::MappedIndex;
+use crate::database::Attributes;
+use crate::database::Properties;
+use crate::space::Space;
+
+/// Default number of positions sorted in memory, before being spilled
+/// to disk.
+pub const RUN_SIZE: usize = 1 << 22;
+
+// Encoded coordinates and value of a position.
+type Record = (Vec<u64>, usize);
+
+// Order records along the Z-order curve, then by value.
+fn record_cmp(a: &Record, b: &Record) -> Ordering {
+    morton_cmp(a.0.len(), |k| a.0[k], |k| b.0[k]).then(a.1.cmp(&b.1))
+}
+
+// Temporary files, removed when dropped.
+struct Scratch {
+    prefix: String,
+    count: usize,
+    files: Vec<String>,
+}
+
+impl Scratch {
+    fn new(prefix: String) -> Self {
+        Scratch {
+            prefix,
+            count: 0,
+            files: vec![],
+        }
+    }
+
+    fn file(&mut self) -> String {
+        let name = format!("{}.{}.tmp", self.prefix, self.count);
+        self.count += 1;
+        self.files.push(name.clone());
+
+        name
+    }
+
+    fn remove(&mut self, name: &str) {
+        self.files.retain(|file| file != name);
+        let _ = fs::remove_file(name);
+    }
+}
+
+impl Drop for Scratch {
+    fn drop(&mut self) {
+        for file in &self.files {
+            let _ = fs::remove_file(file);
+        }
+    }
+}
+
+fn write_record<W>(writer: &mut W, (coordinates, value): Record) -> Result<(), Error>
+where
+    W: Write,
+{
+    for coordinate in coordinates {
+        writer.write_all(&coordinate.to_le_bytes())?;
+    }
+
+    writer.write_all(&(value as u64).to_le_bytes())
+}
+
+// Write sorted records to `to`, and return how many were written.
+fn write_records<I>(to: &str, records: I) -> Result<usize, Error>
+where
+    I: Iterator<Item = Result<Record, Error>>,
+{
+    let mut writer = BufWriter::new(File::create(to)?);
+    let mut count = 0;
+
+    for record in records {
+        write_record(&mut writer, record?)?;
+        count += 1;
+    }
+
+    writer.flush()?;
+
+    Ok(count)
+}
+
+// Read back the records written by `write_records`.
+struct Records {
+    reader: BufReader<File>,
+    dimensions: usize,
+}
+
+impl Records {
+    fn open(from: &str, dimensions: usize) -> Result<Self, Error> {
+        Ok(Records {
+            reader: BufReader::new(File::open(from)?),
+            dimensions,
+        })
+    }
+
+    fn word(&mut self) -> Result<u64, Error> {
+        let mut word = [0u8; 8];
+        self.reader.read_exact(&mut word)?;
+
+        Ok(u64::from_le_bytes(word))
+    }
+
+    fn record(&mut self) -> Result<Record, Error> {
+        let mut coordinates = Vec::with_capacity(self.dimensions);
+        for _ in 0..self.dimensions {
+            coordinates.push(self.word()?);
+        }
+
+        Ok((coordinates, self.word()? as usize))
+    }
+}
+
+impl Iterator for Records {
+    type Item = Result<Record, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.reader.fill_buf() {
+            Ok([]) => None,
+            Ok(_) => Some(self.record()),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+// Smallest record not yet merged of a run.
+struct Head {
+    record: Record,
+    run: usize,
+}
+
+impl PartialEq for Head {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Head {}
+
+impl PartialOrd for Head {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Head {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap is a max-heap, reverse the order to pop the smallest.
+        record_cmp(&other.record, &self.record)
+    }
+}
+
+// Merge sorted runs into a single sorted list, without duplicates.
+struct Merge {
+    runs: Vec<Records>,
+    heads: BinaryHeap<Head>,
+    last: Option<Record>,
+}
+
+impl Merge {
+    fn new(runs: Vec<Records>) -> Result<Self, Error> {
+        let mut merge = Merge {
+            runs,
+            heads: BinaryHeap::new(),
+            last: None,
+        };
+
+        for run in 0..merge.runs.len() {
+            merge.advance(run)?;
+        }
+
+        Ok(merge)
+    }
+
+    fn advance(&mut self, run: usize) -> Result<(), Error> {
+        if let Some(record) = self.runs[run].next() {
+            self.heads.push(Head {
+                record: record?,
+                run,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Iterator for Merge {
+    type Item = Result<Record, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(Head { record, run }) = self.heads.pop() {
+            if let Err(e) = self.advance(run) {
+                return Some(Err(e));
+            }
+
+            if self.last.as_ref() != Some(&record) {
+                self.last = Some(record.clone());
+                return Some(Ok(record));
+            }
+        }
+
+        None
+    }
+}
+
+// Positions of a reference space, sorted in runs of at most `run_size`
+// records.
+struct Runs {
+    dimensions: usize,
+    run_size: usize,
+    buffer: Vec<Record>,
+    files: Vec<String>,
+}
+
+impl Runs {
+    fn new(dimensions: usize, run_size: usize) -> Self {
+        Runs {
+            dimensions,
+            run_size,
+            buffer: vec![],
+            files: vec![],
+        }
+    }
+
+    fn push(&mut self, record: Record, scratch: &mut Scratch) -> Result<(), Error> {
+        self.buffer.push(record);
+
+        if self.buffer.len() >= self.run_size {
+            self.spill(scratch)?;
+        }
+
+        Ok(())
+    }
+
+    fn spill(&mut self, scratch: &mut Scratch) -> Result<(), Error> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+
+        self.buffer.sort_unstable_by(record_cmp);
+        self.buffer.dedup();
+
+        let file = scratch.file();
+        write_records(&file, self.buffer.drain(..).map(Ok))?;
+        self.files.push(file);
+
+        Ok(())
+    }
+
+    // Merge all the runs into `to`, and return the number of records.
+    fn merge(mut self, to: &str, scratch: &mut Scratch) -> Result<usize, Error> {
+        self.spill(scratch)?;
+
+        let runs = self
+            .files
+            .iter()
+            .map(|file| Records::open(file, self.dimensions))
+            .collect::<Result<Vec<_>, _>>()?;
+        let count = write_records(to, Merge::new(runs)?)?;
+
+        for file in &self.files {
+            scratch.remove(file);
+        }
+
+        Ok(count)
+    }
+}
+
+// Remove `shift` bits of precision from sorted records, and write them to
+// `to`. Return the number of records left, once duplicates are removed.
+//
+// Shifting all the coordinates by the same amount preserves the Z-order,
+// so the output is still sorted, once the values of a same position are.
+fn reduce_precision(from: Records, shift: u32, to: &str) -> Result<usize, Error> {
+    let mut writer = BufWriter::new(File::create(to)?);
+    let mut count = 0;
+    let mut group: Option<(Vec<u64>, Vec<usize>)> = None;
+
+    let mut flush = |group: Option<(Vec<u64>, Vec<usize>)>| -> Result<(), Error> {
+        if let Some((coordinates, mut values)) = group {
+            values.sort_unstable();
+            values.dedup();
+            for value in values {
+                write_record(&mut writer, (coordinates.clone(), value))?;
+                count += 1;
+            }
+        }
+
+        Ok(())
+    };
+
+    for record in from {
+        let (mut coordinates, value) = record?;
+        for coordinate in coordinates.iter_mut() {
+            *coordinate >>= shift;
+        }
+
+        match &mut group {
+            Some((current, values)) if *current == coordinates => values.push(value),
+            _ => flush(group.replace((coordinates, vec![value])))?,
+        }
+    }
+    flush(group.take())?;
+
+    writer.flush()?;
+
+    Ok(count)
+}
+
+// Write to a new scratch file the records of `file`, with `shift` bits of
+// precision less, and return its name and the number of records.
+fn coarser(
+    file: &str,
+    dimensions: usize,
+    shift: u32,
+    scratch: &mut Scratch,
+) -> Result<(String, usize), Error> {
+    let to = scratch.file();
+    let count = reduce_precision(Records::open(file, dimensions)?, shift, &to)?;
+
+    Ok((to, count))
+}
+
+// A resolution of the index, stored in a scratch file.
+struct Level {
+    file: String,
+    scale: Vec<u32>,
+    shift: u32,
+}
+
+// Generate the resolutions of the index of a reference space, following
+// the same rules as the in-memory index.
+fn levels(
+    dimensions: usize,
+    full: (String, usize),
+    scales: &Option<Vec<Vec<u32>>>,
+    max_elements: Option<usize>,
+    scratch: &mut Scratch,
+) -> Result<Vec<Level>, Error> {
+    let (mut file, mut count) = full;
+    let mut levels = vec![];
+
+    if let Some(scales) = scales {
+        let mut scales = scales.clone();
+        scales.sort_unstable_by_key(|v| v[0]);
+
+        let mut previous = 0u32;
+        for (i, scale) in scales.into_iter().enumerate() {
+            if scale.len() != dimensions || scale.iter().any(|s| *s != scale[0]) {
+                return Err(invalid_data(format!(
+                    "Scale factors must be identical on all {} axes: {:?}",
+                    dimensions, scale
+                )));
+            }
+
+            let (to, _) = coarser(&file, dimensions, scale[0] - previous, scratch)?;
+            if !levels.iter().any(|level: &Level| level.file == file) {
+                scratch.remove(&file);
+            }
+            previous = scale[0];
+            file = to;
+
+            levels.push(Level {
+                file: file.clone(),
+                scale,
+                shift: (i as u32).min(31),
+            });
+        }
+    } else if let Some(max_elements) = max_elements {
+        let mut shift = 0;
+
+        // The next index should contain at most half the number of
+        // elements of the current index.
+        let mut element_count_target = count / 2;
+
+        levels.push(Level {
+            file: file.clone(),
+            scale: vec![shift; dimensions],
+            shift,
+        });
+
+        // Generate coarser indices, until we reach the expected
+        // max_element value or we can't define bigger bit shift.
+        while count > max_elements && shift <= 31 {
+            let previous = shift;
+            shift += 1;
+
+            let (to, reduced) = coarser(&file, dimensions, 1, scratch)?;
+            if !levels.iter().any(|level| level.file == file) {
+                scratch.remove(&file);
+            }
+            file = to;
+            count = reduced;
+
+            // Skip a resolution if it does not bring down enough the
+            // number of points. It would be a waste of space to store it.
+            if element_count_target < count {
+                continue;
+            }
+            element_count_target = count / 2;
+
+            levels.push(Level {
+                file: file.clone(),
+                scale: vec![shift; dimensions],
+                shift: previous,
+            });
+        }
+    } else {
+        levels.push(Level {
+            file,
+            scale: vec![0; dimensions],
+            shift: 0,
+        });
+    }
+
+    Ok(levels)
+}
+
+// Accumulates the properties and the positions of the objects.
+struct Builder<'s> {
+    spaces: &'s [Space],
+    runs: Vec<Runs>,
+    properties: Vec<Properties>,
+    attributes: Vec<Attributes>,
+    values: HashMap<Properties, usize>,
+    scratch: Scratch,
+}
+
+impl<'s> Builder<'s> {
+    fn add(&mut self, object: model::SpatialObject) -> Result<(), Error> {
+        let next = self.properties.len();
+        let value = *self
+            .values
+            .entry(Properties::from(&object.properties))
+            .or_insert(next);
+        if value == next {
+            self.properties.push(Properties::from(&object.properties));
+            self.attributes.push(Attributes::new());
+        }
+        self.attributes[value].extend(object.properties.attributes);
+
+        for shape in object.shapes {
+            // Only objects whose reference space is known are indexed.
+            if let Some(s) = self
+                .spaces
+                .iter()
+                .position(|space| space.name() == &shape.reference_space)
+            {
+                let positions = shape.rasterise(&self.spaces[s]).map_err(invalid_data)?;
+
+                for position in positions {
+                    self.runs[s].push((coordinates(&position), value), &mut self.scratch)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Use the bincode file `{name}.bin` if it exists, the JSON file
+// `{name}.json` otherwise.
+fn for_each<T, F>(name: &str, f: F) -> Result<(), Error>
+where
+    T: serde::de::DeserializeOwned,
+    F: FnMut(T) -> Result<(), Error>,
+{
+    let fn_bin = format!("{}.bin", name);
+
+    if Path::new(&fn_bin).exists() {
+        bincode::for_each(&fn_bin, f)
+    } else {
+        json::for_each(&format!("{}.json", name), f)
+    }
+}
+
+/// Build a memory-mapped index from input files of arbitrary size.
+///
+/// The input files are read incrementally, and at most `run_size`
+/// positions are kept in memory at any time. The temporary files are
+/// created next to the index.
+///
+/// # Parameters
+///
+///  * `name`:
+///      Index name, this value will also be used to generate file names
+///      as such:
+///       * `.spaces` and `.objects` will be appended for the input
+///         files, followed by `.bin` for bincode files, or `.json`
+///         otherwise.
+///       * `.index` will be appended for the index file.
+///
+///  * `version`:
+///      Parameter to distinguish revisions of an index.
+///
+///  * `scales`, `max_elements`:
+///      Resolutions to generate, see
+///      [bincode::build](../bincode/fn.build.html).
+///
+///  * `run_size`:
+///      Number of positions to sort in memory at once, [RUN_SIZE] if
+///      `None`.
+///
+/// [RUN_SIZE]: constant.RUN_SIZE.html
+pub fn build(
+    name: &str,
+    version: &str,
+    scales: Option<Vec<Vec<u32>>>,
+    max_elements: Option<usize>,
+    run_size: Option<usize>,
+) -> Result<(), Error> {
+    let fn_index = format!("{}.index", name);
+    let run_size = run_size.unwrap_or(RUN_SIZE).max(1);
+
+    let mut spaces: Vec<Space> = vec![];
+    for_each(&format!("{}.spaces", name), |space: model::Space| {
+        spaces.push((&space).try_into().map_err(invalid_data)?);
+        Ok(())
+    })?;
+
+    let mut builder = Builder {
+        spaces: &spaces,
+        runs: spaces
+            .iter()
+            .map(|space| Runs::new(space.axes().len(), run_size))
+            .collect(),
+        properties: vec![],
+        attributes: vec![],
+        values: HashMap::new(),
+        scratch: Scratch::new(fn_index.clone()),
+    };
+
+    // Objects stored with bincode may use the layout preceding attributes.
+    let fn_objects = format!("{}.objects.bin", name);
+    if Path::new(&fn_objects).exists() {
+        bincode::for_each_object(&fn_objects, |object| builder.add(object))?;
+    } else {
+        for_each(&format!("{}.objects", name), |object| builder.add(object))?;
+    }
+
+    let Builder {
+        runs,
+        properties,
+        attributes,
+        mut scratch,
+        ..
+    } = builder;
+
+    // We cannot return less that the total number of individual Ids stored
+    // in the index for a full-volume query.
+    let limit = max_elements.map(|elements| elements.max(properties.len()));
+
+    // The property table is stored ordered by identifier, so renumber the
+    // values accordingly.
+    let mut order = (0..properties.len()).collect::<Vec<_>>();
+    order.sort_unstable_by_key(|&value| properties[value].key());
+    let mut remap = vec![0; order.len()];
+    for (i, &value) in order.iter().enumerate() {
+        remap[value] = i;
+    }
+
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(&fn_index)?;
+    mapped::write_header(&mut file)?;
+
+    let mut position = mapped::HEADER;
+    let mut space_db = vec![];
+
+    for (space, runs) in spaces.iter().zip(runs) {
+        let dimensions = space.axes().len();
+        let full = scratch.file();
+        let count = runs.merge(&full, &mut scratch)?;
+        let levels = levels(dimensions, (full, count), &scales, limit, &mut scratch)?;
+
+        // Compute threshold volume as Vt = V / 2^(max_shift) * 2^shift
+        //  => the smaller shift is, the smaller the threshold is and the
+        //     higher the resolution is.
+        let space_volume = space.volume();
+        let max_shift = levels.last().map_or(31, |level| level.shift);
+
+        let mut resolutions = vec![];
+        for level in levels {
+            let size = MappedIndex::write_sorted(
+                &mut file,
+                dimensions,
+                properties.len(),
+                Records::open(&level.file, dimensions)?.map(|record| match record {
+                    Ok((coordinates, value)) => Ok((coordinates, remap[value])),
+                    Err(e) => Err(e.into()),
+                }),
+            )
+            .map_err(invalid_data)?;
+            scratch.remove(&level.file);
+
+            resolutions.push(mapped::Resolution {
+                threshold_volume: space_volume / f64::from(1 << (max_shift - level.shift)),
+                scale: level.scale,
+                offset: position,
+            });
+            position += size;
+        }
+
+        space_db.push(mapped::SpaceSection {
+            reference_space: space.name().clone(),
+            resolutions,
+        });
+    }
+
+    let mut writer = BufWriter::new(file);
+    let (properties, attributes, position) = mapped::write_tables(
+        &mut writer,
+        order.iter().map(|&value| &properties[value]),
+        order.iter().map(|&value| &attributes[value]),
+        position,
+    )?;
+
+    let footer = mapped::Footer {
+        title: name.to_string(),
+        version: version.to_string(),
+        crate_version: env!("CARGO_PKG_VERSION").to_string(),
+        scales,
+        max_elements,
+        spaces,
+        properties,
+        attributes,
+        space_db,
+    };
+
+    mapped::write_footer(&mut writer, &footer, position)?;
+    writer.flush()?;
+    drop(writer);
+
+    mapped::seal(&fn_index)
+}
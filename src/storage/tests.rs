@@ -0,0 +1,1342 @@
+use std::fs;
+
+use super::*;
+use crate::space::Axis;
+use crate::space::CoordinateSystem;
+use crate::space::NumberSet;
+use crate::space::Shape;
+use crate::space::Space;
+use crate::Core;
+use crate::CoreQueryParameters;
+use crate::DataBase;
+use crate::Value;
+
+fn get_space() -> Space {
+    get_named_space("space")
+}
+
+fn get_named_space(name: &str) -> Space {
+    let axis = |unit_vector: Vec<f64>| {
+        Axis::new("mm", unit_vector, NumberSet::R, 0.0, 100.0, 1000).unwrap()
+    };
+
+    Space::new(
+        name,
+        CoordinateSystem::new(
+            vec![0.0, 0.0, 0.0],
+            vec![
+                axis(vec![1.0, 0.0, 0.0]),
+                axis(vec![0.0, 1.0, 0.0]),
+                axis(vec![0.0, 0.0, 1.0]),
+            ],
+        ),
+    )
+}
+
+fn get_object(id: &str, points: &[[f64; 3]]) -> model::SpatialObject {
+    model::SpatialObject {
+        properties: model::Properties {
+            type_name: "Feature".to_string(),
+            id: id.to_string(),
+            attributes: Default::default(),
+        },
+        shapes: points
+            .iter()
+            .map(|point| model::v1::Shape {
+                type_name: "Point".to_string(),
+                reference_space: "space".to_string(),
+                vertices: vec![point.to_vec()],
+            })
+            .collect(),
+    }
+}
+
+fn get_core() -> (Vec<Space>, Core) {
+    let spaces = vec![get_space()];
+
+    let mut labelled = get_object("c", &[[50.0, 50.0, 50.0]]);
+    labelled
+        .properties
+        .attributes
+        .insert("area".to_string(), Value::Text("V1".to_string()));
+
+    let objects = vec![
+        get_object("b", &[[10.0, 10.0, 10.0], [11.0, 11.0, 11.0]]),
+        get_object("a", &[[12.0, 12.0, 12.0], [90.0, 10.0, 10.0]]),
+        labelled,
+    ];
+
+    let core = model::build_index("core", "v1", &spaces, &objects, None, Some(1), None).unwrap();
+
+    (spaces, core)
+}
+
+// Path of a temporary file, removed when dropped.
+struct Temporary(String);
+
+impl Temporary {
+    fn new(name: &str) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("mercator_db-{}-{}", std::process::id(), name));
+
+        Temporary(path.to_string_lossy().into_owned())
+    }
+}
+
+impl Drop for Temporary {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+// Results of a query, as sorted strings.
+fn summarise<T>(results: Vec<(&String, Vec<T>)>) -> Vec<String>
+where
+    T: std::fmt::Debug,
+{
+    let mut summary = results
+        .iter()
+        .flat_map(|(space, v)| v.iter().map(move |r| format!("{} {:?}", space, r)))
+        .collect::<Vec<_>>();
+    summary.sort_unstable();
+
+    summary
+}
+
+#[test]
+pub fn mapped_round_trip() {
+    let (spaces, core) = get_core();
+    let file = Temporary::new("mapped.index");
+
+    mapped::store(&spaces, &core, None, None, &file.0).unwrap();
+    assert!(mapped::is_mapped(&file.0).unwrap());
+
+    let (mapped_spaces, mapped_core) = DataBase::load_core(&file.0).unwrap();
+    assert_eq!(mapped_spaces, spaces);
+    assert_eq!(mapped_core.keys().unwrap(), core.keys().unwrap());
+
+    let db = DataBase::new(spaces.clone(), vec![core]).unwrap();
+    let mapped_db = DataBase::new(spaces, vec![mapped_core]).unwrap();
+    let (core, mapped_core) = (db.core("core").unwrap(), mapped_db.core("core").unwrap());
+
+    let parameters = CoreQueryParameters {
+        db: &db,
+        output_space: None,
+        threshold_volume: Some(0.0),
+        view_port: &None,
+        resolution: &None,
+        filters: &[],
+    };
+    let mapped_parameters = CoreQueryParameters {
+        db: &mapped_db,
+        ..parameters
+    };
+
+    for id in &["a", "b", "c", "d"] {
+        assert_eq!(
+            summarise(core.get_by_id(&parameters, *id, None).unwrap()),
+            summarise(
+                mapped_core
+                    .get_by_id(&mapped_parameters, *id, None)
+                    .unwrap()
+            )
+        );
+    }
+
+    let space = db.space("space").unwrap();
+    let shape = Shape::BoundingBox(
+        space.encode(&[0.0, 0.0, 0.0]).unwrap(),
+        space.encode(&[20.0, 20.0, 20.0]).unwrap(),
+    );
+    let results = summarise(core.get_by_shape(&parameters, &shape, "space").unwrap());
+    assert_eq!(results.len(), 3);
+    assert_eq!(
+        results,
+        summarise(
+            mapped_core
+                .get_by_shape(&mapped_parameters, &shape, "space")
+                .unwrap()
+        )
+    );
+
+    let labelled = crate::Properties::Feature("c".to_string());
+    assert_eq!(
+        format!("{:?}", mapped_core.attributes(&labelled).unwrap()),
+        "Some({\"area\": Text(\"V1\")})"
+    );
+
+    // The positions of a memory-mapped index are not stored with bincode.
+    assert!(::bincode::serialize(mapped_core.as_ref()).is_err());
+}
+
+// Apply `f` to the content of a file.
+fn alter<F>(name: &str, f: F)
+where
+    F: FnOnce(&mut Vec<u8>),
+{
+    let mut data = fs::read(name).unwrap();
+    f(&mut data);
+    fs::write(name, data).unwrap();
+}
+
+// Error message of a failed load.
+fn error<T>(result: Result<T, std::io::Error>) -> String {
+    result.err().unwrap().to_string()
+}
+
+#[test]
+pub fn index_header() {
+    let (spaces, core) = get_core();
+    let file = Temporary::new("header.index");
+
+    let scales = Some(vec![vec![0, 0, 0], vec![2, 2, 2]]);
+    bincode::store_index(&spaces, &core, scales.clone(), Some(10), &file.0).unwrap();
+
+    let header = bincode::load_header(&file.0).unwrap();
+    assert_eq!(header.format_version(), bincode::FORMAT_VERSION);
+    assert_eq!(header.crate_version(), env!("CARGO_PKG_VERSION"));
+    assert_eq!(header.scales(), &scales);
+    assert_eq!(header.max_elements(), Some(10));
+
+    let (loaded_spaces, loaded_core) = bincode::load_index(&file.0).unwrap();
+    assert_eq!(loaded_spaces, spaces);
+    assert_eq!(loaded_core.keys().unwrap(), core.keys().unwrap());
+
+    // Corrupted data.
+    alter(&file.0, |data| *data.last_mut().unwrap() ^= 1);
+    assert!(error(bincode::load_index(&file.0)).contains("checksum mismatch"));
+
+    // Truncated data.
+    alter(&file.0, |data| {
+        data.pop();
+    });
+    assert!(error(bincode::load_index(&file.0)).starts_with("Truncated index"));
+
+    // Unknown format version, stored right after the magic number.
+    bincode::store_index(&spaces, &core, None, None, &file.0).unwrap();
+    alter(&file.0, |data| data[8] = data[8].wrapping_add(1));
+    assert!(error(bincode::load_index(&file.0)).starts_with("Unsupported format version"));
+}
+
+#[test]
+pub fn data_header() {
+    let file = Temporary::new("data.bin");
+    let data = vec![1u32, 2, 3];
+
+    let read = |name: &str| -> Result<Vec<u32>, std::io::Error> {
+        let mut values = vec![];
+        bincode::for_each(name, |value: u32| {
+            values.push(value);
+            Ok(())
+        })?;
+
+        Ok(values)
+    };
+
+    bincode::store(&data, &file.0).unwrap();
+    assert!(fs::read(&file.0).unwrap().starts_with(bincode::MAGIC));
+    assert_eq!(bincode::load::<Vec<u32>>(&file.0).unwrap(), data);
+    assert_eq!(read(&file.0).unwrap(), data);
+
+    alter(&file.0, |data| *data.last_mut().unwrap() ^= 1);
+    assert!(error(bincode::load::<Vec<u32>>(&file.0)).contains("checksum mismatch"));
+    assert!(error(read(&file.0)).starts_with("Corrupted file"));
+
+    // Files written without header are still read.
+    fs::write(&file.0, ::bincode::serialize(&data).unwrap()).unwrap();
+    assert_eq!(bincode::load::<Vec<u32>>(&file.0).unwrap(), data);
+    assert_eq!(read(&file.0).unwrap(), data);
+}
+
+#[test]
+pub fn mapped_header() {
+    let (spaces, core) = get_core();
+    let file = Temporary::new("header.mapped");
+
+    mapped::store(&spaces, &core, None, None, &file.0).unwrap();
+    assert!(mapped::load(&file.0).is_ok());
+    assert!(mapped::verify(&file.0).is_ok());
+
+    // Corrupted data is only detected when verifying the file.
+    alter(&file.0, |data| data[mapped::HEADER] ^= 1);
+    assert!(error(mapped::verify(&file.0)).contains("checksum mismatch"));
+
+    // Truncated data.
+    mapped::store(&spaces, &core, None, None, &file.0).unwrap();
+    alter(&file.0, |data| data.truncate(data.len() - 1));
+    assert!(error(mapped::load(&file.0)).starts_with("Truncated memory-mapped index"));
+    assert!(error(mapped::verify(&file.0)).starts_with("Truncated memory-mapped index"));
+
+    // Unknown format version, stored right after the magic number.
+    mapped::store(&spaces, &core, None, None, &file.0).unwrap();
+    alter(&file.0, |data| data[8] = data[8].wrapping_add(1));
+    assert!(error(mapped::load(&file.0)).starts_with("Unsupported memory-mapped index version"));
+}
+
+#[test]
+pub fn streaming_build() {
+    let (reference, streamed) = (Temporary::new("reference"), Temporary::new("streamed"));
+    let files = ["spaces.bin", "objects.bin", "index"]
+        .iter()
+        .flat_map(|suffix| {
+            vec![
+                Temporary(format!("{}.{}", reference.0, suffix)),
+                Temporary(format!("{}.{}", streamed.0, suffix)),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let spaces = vec![model::Space::from(&get_space())];
+
+    // Several positions per identifier, spread over the space, so that
+    // the runs overlap along the Z-order curve.
+    let objects = (0..60)
+        .map(|i| {
+            let points = (0..i % 4 + 1)
+                .map(|j| {
+                    let k = (i * 7 + j * 13) as f64;
+                    [k % 97.0, (k * 3.0) % 89.0, (k * 5.0) % 83.0]
+                })
+                .collect::<Vec<_>>();
+
+            get_object(&format!("id{}", i % 25), &points)
+        })
+        .collect::<Vec<_>>();
+
+    for name in &[&reference.0, &streamed.0] {
+        bincode::store(&spaces, &format!("{}.spaces.bin", name)).unwrap();
+        bincode::store(&objects, &format!("{}.objects.bin", name)).unwrap();
+    }
+
+    let scales = Some(vec![vec![0, 0, 0], vec![3, 3, 3]]);
+    mapped::build(&reference.0, "v1", scales.clone(), None, None).unwrap();
+    // Runs of 3 positions, to exercise the merge of the sorted runs.
+    streaming::build(&streamed.0, "v1", scales, None, Some(3)).unwrap();
+
+    // The temporary files have been removed.
+    let directory = std::env::temp_dir();
+    let prefix = streamed.0.rsplit('/').next().unwrap();
+    assert!(!fs::read_dir(directory).unwrap().any(|entry| {
+        let name = entry.unwrap().file_name().into_string().unwrap();
+        name.starts_with(prefix) && name.ends_with(".tmp")
+    }));
+
+    let (spaces, reference_core) = DataBase::load_core(&files[4].0).unwrap();
+    let (_, streamed_core) = DataBase::load_core(&files[5].0).unwrap();
+    assert_eq!(
+        streamed_core.keys().unwrap(),
+        reference_core.keys().unwrap()
+    );
+
+    let db = DataBase::new(spaces, vec![reference_core, streamed_core]).unwrap();
+    let reference_core = db.core(&reference.0).unwrap();
+    let streamed_core = db.core(&streamed.0).unwrap();
+    let space = db.space("space").unwrap();
+
+    for threshold_volume in &[0.0, 1e4, std::f64::MAX] {
+        let parameters = CoreQueryParameters {
+            db: &db,
+            output_space: None,
+            threshold_volume: Some(*threshold_volume),
+            view_port: &None,
+            resolution: &None,
+            filters: &[],
+        };
+
+        for (low, high) in &[(0.0, 99.9), (10.0, 40.0)] {
+            let shape = Shape::BoundingBox(
+                space.encode(&[*low; 3]).unwrap(),
+                space.encode(&[*high; 3]).unwrap(),
+            );
+            let results = summarise(
+                reference_core
+                    .get_by_shape(&parameters, &shape, "space")
+                    .unwrap(),
+            );
+            assert!(*threshold_volume > 0.0 || !results.is_empty());
+            assert_eq!(
+                results,
+                summarise(
+                    streamed_core
+                        .get_by_shape(&parameters, &shape, "space")
+                        .unwrap()
+                )
+            );
+        }
+
+        for id in &["id0", "id7", "id24"] {
+            assert_eq!(
+                summarise(reference_core.get_by_id(&parameters, *id, None).unwrap()),
+                summarise(streamed_core.get_by_id(&parameters, *id, None).unwrap())
+            );
+        }
+    }
+}
+
+#[test]
+pub fn legacy_objects() {
+    let file = Temporary::new("objects.bin");
+
+    // Objects written before the attributes were introduced, without
+    // header. Bincode stores structures as tuples of their fields.
+    let shape = &get_object("a", &[[1.0, 2.0, 3.0]]).shapes[0];
+    let legacy = vec![(("Feature", "a"), vec![shape])];
+    fs::write(&file.0, ::bincode::serialize(&legacy).unwrap()).unwrap();
+
+    let objects = bincode::load_objects(&file.0).unwrap();
+    assert_eq!(objects.len(), 1);
+    assert_eq!(objects[0].properties.id, "a");
+    assert!(objects[0].properties.attributes.is_empty());
+    assert_eq!(objects[0].shapes[0].vertices, vec![vec![1.0, 2.0, 3.0]]);
+
+    // Objects stored with a header keep their attributes.
+    let mut object = get_object("b", &[[1.0, 2.0, 3.0]]);
+    object
+        .properties
+        .attributes
+        .insert("area".to_string(), Value::Integer(3));
+    bincode::store(vec![&object], &file.0).unwrap();
+
+    let objects = bincode::load_objects(&file.0).unwrap();
+    assert_eq!(
+        format!("{:?}", objects[0].properties.attributes),
+        "{\"area\": Integer(3)}"
+    );
+}
+
+#[test]
+pub fn result_attributes() {
+    let (_, core) = get_core();
+
+    let mut properties = model::Properties {
+        type_name: "Feature".to_string(),
+        id: "c".to_string(),
+        attributes: Default::default(),
+    };
+    properties.load_attributes(&core).unwrap();
+    assert_eq!(
+        format!("{:?}", properties.attributes),
+        "{\"area\": Text(\"V1\")}"
+    );
+
+    // Unknown identifiers are left untouched.
+    properties.id = "d".to_string();
+    properties.load_attributes(&core).unwrap();
+    assert_eq!(properties.attributes.len(), 1);
+}
+
+#[test]
+pub fn compact_output() {
+    let spaces = vec![get_space(), get_named_space("other")];
+    let db = DataBase::new(spaces, vec![]).unwrap();
+
+    let properties = crate::Properties::Feature("a".to_string());
+    let space_id = "space".to_string();
+    let list = || {
+        vec![(
+            &space_id,
+            vec![
+                (vec![10.0, 10.0, 10.0].into(), &properties),
+                (vec![10.1, 10.0, 10.0].into(), &properties),
+            ],
+        )]
+    };
+
+    // Adjacent cells are merged, and given by the centers of the corners.
+    let objects = model::v2::to_spatial_objects_compact(&db, None, list()).unwrap();
+    assert_eq!(objects.len(), 1);
+    match &objects[0].volumes[0].shapes[..] {
+        [model::v2::Shape::BoundingBoxes(boxes)] => {
+            assert_eq!(boxes.len(), 1);
+            let (lower, higher) = &boxes[0];
+            assert!((lower[0] - 10.05).abs() < 1e-9 && (higher[0] - 10.15).abs() < 1e-9);
+            assert!((lower[1] - 10.05).abs() < 1e-9 && (higher[1] - 10.05).abs() < 1e-9);
+        }
+        shapes => panic!("unexpected shapes {:?}", shapes),
+    }
+
+    // Positions rebased in another space are kept as points.
+    let objects = model::v2::to_spatial_objects_compact(&db, Some("other"), list()).unwrap();
+    assert_eq!(
+        format!("{:?}", objects[0].volumes[0].shapes),
+        "[Points([[10.0, 10.0, 10.0], [10.1, 10.0, 10.0]])]"
+    );
+
+    // Unless it is the space the positions are listed under.
+    let objects = model::v2::to_spatial_objects_compact(&db, Some("space"), list()).unwrap();
+    assert_eq!(objects[0].volumes[0].shapes.len(), 1);
+}
+
+// Write a single file NIfTI-1 image of `values.len()` x 1 x 1 voxels of
+// 32-bit floating point values, with voxels of 1 mm.
+fn write_nifti(name: &str, values: &[f32]) {
+    let mut data = vec![0u8; 352];
+    data[0..4].copy_from_slice(&348i32.to_le_bytes());
+    data[40..42].copy_from_slice(&3i16.to_le_bytes());
+    data[42..44].copy_from_slice(&(values.len() as i16).to_le_bytes());
+    data[44..46].copy_from_slice(&1i16.to_le_bytes());
+    data[46..48].copy_from_slice(&1i16.to_le_bytes());
+    data[70..72].copy_from_slice(&16i16.to_le_bytes());
+    for k in 1..4 {
+        data[76 + 4 * k..80 + 4 * k].copy_from_slice(&1f32.to_le_bytes());
+    }
+    data[108..112].copy_from_slice(&352f32.to_le_bytes());
+    data[344..348].copy_from_slice(b"n+1\0");
+
+    for value in values {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fs::write(name, data).unwrap();
+}
+
+#[test]
+pub fn nifti_labels() {
+    let file = Temporary::new("labels.nii");
+
+    write_nifti(&file.0, &[2.0, 0.0, 1.0, 2.0, -0.0]);
+    let objects = nifti::load(&file.0, "space", "Feature").unwrap();
+    let labels = objects
+        .iter()
+        .map(|object| {
+            let vertices = &object.shapes[0].vertices;
+            format!("{} {:?}", object.properties.id, vertices)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        labels,
+        vec![
+            "1 [[2.0, 0.0, 0.0]]",
+            "2 [[0.0, 0.0, 0.0], [3.0, 0.0, 0.0]]"
+        ]
+    );
+
+    for invalid in &[1.5, std::f32::NAN, std::f32::INFINITY] {
+        write_nifti(&file.0, &[1.0, *invalid]);
+        assert!(nifti::load(&file.0, "space", "Feature").is_err());
+    }
+}
+
+#[test]
+pub fn nearest() {
+    let (spaces, core) = get_core();
+    let db = DataBase::new(spaces, vec![core]).unwrap();
+    let core = db.core("core").unwrap();
+
+    let parameters = CoreQueryParameters {
+        db: &db,
+        output_space: None,
+        threshold_volume: None,
+        view_port: &None,
+        resolution: &None,
+        filters: &[],
+    };
+    let nearest = |position: [f64; 3], k| {
+        core.get_nearest(&parameters, &position.to_vec().into(), k, "space")
+            .unwrap()
+            .into_iter()
+            .map(|(_, position, properties, distance)| {
+                let position: Vec<f64> = position.into();
+                (properties.id().to_string(), position, distance)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // Each identifier is returned once, at its closest position, sorted
+    // by distance.
+    let results = nearest([0.0; 3], 2);
+    assert_eq!(results.len(), 2);
+    assert_eq!((results[0].0.as_str(), results[1].0.as_str()), ("b", "a"));
+    assert!((results[0].1[0] - 10.0).abs() < 0.2);
+    // Distances are measured in the Universe, in meters.
+    assert!((results[0].2 - 300f64.sqrt() * 1e-3).abs() < 2e-4);
+    assert!((results[1].2 - 432f64.sqrt() * 1e-3).abs() < 2e-4);
+
+    // The search stops once it covers the whole space, even when there
+    // are less than `k` identifiers.
+    let results = nearest([0.0; 3], 10);
+    assert_eq!(
+        results.iter().map(|r| r.0.as_str()).collect::<Vec<_>>(),
+        vec!["b", "a", "c"]
+    );
+
+    // The search grows from any corner, and reaches far positions.
+    let results = nearest([99.0, 0.0, 0.0], 1);
+    assert_eq!(results[0].0, "a");
+    assert!((results[0].1[0] - 90.0).abs() < 0.2);
+    let results = nearest([99.0; 3], 1);
+    assert_eq!(results[0].0, "c");
+
+    assert!(nearest([0.0; 3], 0).is_empty());
+}
+
+#[test]
+pub fn distance_join() {
+    // Half of the space, as common space.
+    let axis =
+        |unit_vector: Vec<f64>| Axis::new("mm", unit_vector, NumberSet::R, 0.0, 50.0, 500).unwrap();
+    let half = Space::new(
+        "half",
+        CoordinateSystem::new(
+            vec![0.0, 0.0, 0.0],
+            vec![
+                axis(vec![1.0, 0.0, 0.0]),
+                axis(vec![0.0, 1.0, 0.0]),
+                axis(vec![0.0, 0.0, 1.0]),
+            ],
+        ),
+    );
+
+    let spaces = vec![get_space(), half];
+    let core = |name: &str, objects: &[model::SpatialObject]| {
+        model::build_index(name, "v1", &spaces, objects, None, Some(1), None).unwrap()
+    };
+    let left = core(
+        "left",
+        &[
+            get_object("l", &[[45.0, 45.0, 45.0]]),
+            get_object("m", &[[80.0, 80.0, 80.0]]),
+        ],
+    );
+    let right = core(
+        "right",
+        &[
+            get_object("r1", &[[46.0, 45.0, 45.0]]),
+            get_object("r2", &[[60.0, 45.0, 45.0]]),
+            get_object("r3", &[[81.0, 80.0, 80.0]]),
+        ],
+    );
+    let db = DataBase::new(spaces.clone(), vec![left, right]).unwrap();
+
+    let join = |output_space, distance| {
+        let parameters = CoreQueryParameters {
+            db: &db,
+            output_space,
+            threshold_volume: None,
+            view_port: &None,
+            resolution: &None,
+            filters: &[],
+        };
+        db.join_by_distance(&parameters, "left", "right", distance)
+            .unwrap()
+            .into_iter()
+            .map(|(a, b, d)| (format!("{}-{}", a.id(), b.id()), d))
+            .collect::<Vec<_>>()
+    };
+
+    // In the Universe, distances are in meters.
+    let pairs = join(None, 0.0011);
+    assert_eq!(
+        pairs.iter().map(|p| p.0.as_str()).collect::<Vec<_>>(),
+        vec!["l-r1", "m-r3"]
+    );
+    assert!((pairs[0].1 - 0.001).abs() < 1e-4);
+    assert!(join(None, 0.0009).is_empty());
+
+    // Positions outside of the common space are ignored, instead of being
+    // clipped to its bounds, which would bring them closer.
+    let pairs = join(Some("half"), 10.0);
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0].0, "l-r1");
+    assert!((pairs[0].1 - 1.0).abs() < 0.2);
+}
+
+#[test]
+pub fn overlap_join() {
+    // Cells twice as large as in the default space.
+    let axis = |unit_vector: Vec<f64>| {
+        Axis::new("mm", unit_vector, NumberSet::R, 0.0, 100.0, 500).unwrap()
+    };
+    let coarse = Space::new(
+        "coarse",
+        CoordinateSystem::new(
+            vec![0.0, 0.0, 0.0],
+            vec![
+                axis(vec![1.0, 0.0, 0.0]),
+                axis(vec![0.0, 1.0, 0.0]),
+                axis(vec![0.0, 0.0, 1.0]),
+            ],
+        ),
+    );
+
+    let spaces = vec![get_space(), coarse];
+    let mut b = get_object("b", &[[10.0, 10.0, 10.0]]);
+    b.shapes[0].reference_space = "coarse".to_string();
+
+    let core = |name: &str, objects: &[model::SpatialObject]| {
+        model::build_index(name, "v1", &spaces, objects, None, Some(1), None).unwrap()
+    };
+    let left = core("left", &[get_object("a", &[[10.0, 10.0, 10.0]])]);
+    let right = core("right", &[b]);
+    let db = DataBase::new(spaces.clone(), vec![left, right]).unwrap();
+
+    let parameters = CoreQueryParameters {
+        db: &db,
+        output_space: None,
+        threshold_volume: None,
+        view_port: &None,
+        resolution: &None,
+        filters: &[],
+    };
+
+    // The cells overlap, whichever space they are compared in, as the
+    // comparison is made at the coarsest cell size of both datasets.
+    for space_id in &["space", "coarse"] {
+        let pairs = db
+            .join_by_overlap(&parameters, "left", "right", space_id)
+            .unwrap()
+            .into_iter()
+            .map(|(a, b, c)| (a.id().to_string(), b.id().to_string(), c))
+            .collect::<Vec<_>>();
+        assert_eq!(pairs, vec![("a".to_string(), "b".to_string(), 1)]);
+    }
+}
+
+#[test]
+pub fn histogram() {
+    let spaces = vec![get_space()];
+    let objects = vec![
+        get_object("a", &[[10.0, 10.0, 10.0], [10.15, 10.0, 10.0]]),
+        get_object("b", &[[10.25, 10.0, 10.0], [50.0, 50.0, 50.0]]),
+    ];
+    let scales = Some(vec![vec![0, 0, 0], vec![3, 3, 3]]);
+    let core = model::build_index("core", "v1", &spaces, &objects, scales, None, None).unwrap();
+    let db = DataBase::new(spaces, vec![core]).unwrap();
+    let core = db.core("core").unwrap();
+    let space = db.space("space").unwrap();
+
+    let shape = Shape::BoundingBox(
+        space.encode(&[0.0; 3]).unwrap(),
+        space.encode(&[99.9; 3]).unwrap(),
+    );
+    let histogram = |resolution| {
+        let parameters = CoreQueryParameters {
+            db: &db,
+            output_space: None,
+            threshold_volume: None,
+            view_port: &None,
+            resolution: &Some(resolution),
+            filters: &[],
+        };
+        let mut histogram = core
+            .histogram_by_shape(&parameters, &shape, "space")
+            .unwrap();
+        assert_eq!(histogram.len(), 1);
+
+        histogram
+            .pop()
+            .unwrap()
+            .1
+            .into_iter()
+            .map(|(position, count)| {
+                let position: Vec<f64> = position.into();
+                let position = position
+                    .iter()
+                    .map(|c| format!("{:.1}", c))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                (position, count)
+            })
+            .collect::<Vec<_>>()
+    };
+    let bin = |position: &str, count| (position.to_string(), count);
+
+    // One bin per position at full resolution.
+    assert_eq!(
+        histogram(vec![0, 0, 0]),
+        vec![
+            bin("10.0 10.0 10.0", 1),
+            bin("10.1 10.0 10.0", 1),
+            bin("10.2 10.0 10.0", 1),
+            bin("50.0 50.0 50.0", 1),
+        ]
+    );
+
+    // Cells of 8 steps, located at their lowest corner, where each
+    // identifier is counted once.
+    assert_eq!(
+        histogram(vec![3, 3, 3]),
+        vec![bin("9.6 9.6 9.6", 2), bin("49.6 49.6 49.6", 1)]
+    );
+}
+
+#[test]
+pub fn insert() {
+    let spaces = vec![get_space(), get_named_space("other")];
+    let scales = Some(vec![vec![0, 0, 0], vec![3, 3, 3]]);
+    let db = DataBase::new(spaces.clone(), vec![]).unwrap();
+
+    let mut moved = get_object("c", &[[30.0, 30.0, 30.0]]);
+    moved.shapes[0].reference_space = "other".to_string();
+    let objects = vec![
+        get_object("a", &[[10.0, 10.0, 10.0], [10.15, 10.0, 10.0]]),
+        get_object("b", &[[50.0, 50.0, 50.0]]),
+        get_object("a", &[[10.25, 10.0, 10.0]]),
+        moved,
+    ];
+
+    let build = |objects: &[model::SpatialObject]| {
+        model::build_index("core", "v1", &spaces, objects, scales.clone(), None, None).unwrap()
+    };
+    let reference = build(&objects);
+    let mut core = build(&objects[..2]);
+    core.insert(&db, &objects[2..]).unwrap();
+    assert_eq!(core.keys().unwrap(), reference.keys().unwrap());
+
+    // Every resolution of the index, including the ones of the space
+    // which was not used yet, holds the same positions.
+    for resolution in &[vec![0, 0, 0], vec![3, 3, 3]] {
+        let parameters = CoreQueryParameters {
+            db: &db,
+            output_space: None,
+            threshold_volume: None,
+            view_port: &None,
+            resolution: &Some(resolution.clone()),
+            filters: &[],
+        };
+
+        for id in &["a", "b", "c"] {
+            assert_eq!(
+                summarise(core.get_by_id(&parameters, *id, None).unwrap()),
+                summarise(reference.get_by_id(&parameters, *id, None).unwrap())
+            );
+        }
+    }
+
+    // Unknown reference spaces are rejected, without modifying anything.
+    let mut unknown = get_object("e", &[[30.0, 30.0, 30.0]]);
+    unknown.shapes[0].reference_space = "unknown".to_string();
+    let objects = vec![get_object("d", &[[20.0, 20.0, 20.0]]), unknown];
+    match core.insert(&db, &objects) {
+        Err(crate::Error::UnknownSpace(name)) => assert_eq!(name, "unknown"),
+        r => panic!("unexpected result {:?}", r),
+    }
+    assert_eq!(core.keys().unwrap(), reference.keys().unwrap());
+
+    // Including spaces which are not registered in the database.
+    let db = DataBase::new(vec![get_space()], vec![]).unwrap();
+    let mut unregistered = get_object("e", &[[30.0, 30.0, 30.0]]);
+    unregistered.shapes[0].reference_space = "other".to_string();
+    let objects = vec![get_object("d", &[[20.0, 20.0, 20.0]]), unregistered];
+    match core.insert(&db, &objects) {
+        Err(crate::Error::UnknownSpace(name)) => assert_eq!(name, "other"),
+        r => panic!("unexpected result {:?}", r),
+    }
+    let parameters = CoreQueryParameters {
+        db: &db,
+        output_space: None,
+        threshold_volume: None,
+        view_port: &None,
+        resolution: &None,
+        filters: &[],
+    };
+    assert!(core.get_by_id(&parameters, "d", None).unwrap().is_empty());
+}
+
+#[test]
+pub fn remove() {
+    let spaces = vec![get_space()];
+    let objects = vec![
+        get_object("a", &[[12.0, 12.0, 12.0], [90.0, 10.0, 10.0]]),
+        get_object("b", &[[50.0, 50.0, 50.0]]),
+        get_object("label", &[[12.0, 12.0, 12.0]]),
+    ];
+    let mut core =
+        model::build_index("core", "v1", &spaces, &objects, None, Some(1), None).unwrap();
+
+    let db = DataBase::new(spaces, vec![]).unwrap();
+    let space = db.space("space").unwrap();
+    let parameters = CoreQueryParameters {
+        db: &db,
+        output_space: None,
+        threshold_volume: None,
+        view_port: &None,
+        resolution: &None,
+        filters: &[],
+    };
+    let shape = Shape::BoundingBox(
+        space.encode(&[0.0; 3]).unwrap(),
+        space.encode(&[99.9; 3]).unwrap(),
+    );
+
+    // Identifiers of the results of each kind of query.
+    let ids = |results: Vec<(&String, Vec<(crate::space::Position, &crate::Properties)>)>| {
+        let mut ids = results
+            .iter()
+            .flat_map(|(_, r)| r.iter().map(|(_, p)| p.id().to_string()))
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    };
+    let by_shape = |core: &Core| ids(core.get_by_shape(&parameters, &shape, "space").unwrap());
+    let by_label = |core: &Core, id| ids(core.get_by_label(&parameters, id, None).unwrap());
+    let by_id = |core: &Core, id| summarise(core.get_by_id(&parameters, id, None).unwrap());
+    let keys = |core: &Core| {
+        core.keys()
+            .unwrap()
+            .iter()
+            .map(|p| p.id().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(by_shape(&core), vec!["a", "b", "label"]);
+    assert_eq!(by_label(&core, "label"), vec!["a"]);
+    assert_eq!(by_id(&core, "a").len(), 2);
+
+    assert!(core.remove("a", None).unwrap());
+    assert!(!core.remove("a", None).unwrap());
+    assert!(!core.remove("b", Some("Cell")).unwrap());
+
+    assert_eq!(keys(&core), vec!["b", "label"]);
+    assert!(by_id(&core, "a").is_empty());
+    assert_eq!(by_shape(&core), vec!["b", "label"]);
+    assert!(by_label(&core, "label").is_empty());
+    assert!(by_label(&core, "a").is_empty());
+
+    // The identifier can be registered again, without its previous
+    // positions.
+    core.insert(&db, &[get_object("a", &[[20.0, 20.0, 20.0]])])
+        .unwrap();
+    assert_eq!(keys(&core), vec!["a", "b", "label"]);
+    assert_eq!(
+        by_id(&core, "a"),
+        vec!["space Position3([CoordinateF64(20.0), CoordinateF64(20.0), CoordinateF64(20.0)])"]
+    );
+    assert_eq!(by_shape(&core), vec!["a", "b", "label"]);
+    assert!(by_label(&core, "label").is_empty());
+}
+
+#[test]
+pub fn registry() {
+    let (spaces, core) = get_core();
+    let db = DataBase::new(spaces.clone(), vec![]).unwrap();
+    db.add_core(spaces.clone(), core).unwrap();
+    assert_eq!(db.core_keys(), vec!["core"]);
+
+    // Names can not be used twice.
+    let (_, core) = get_core();
+    match db.add_core(spaces.clone(), core) {
+        Err(crate::Error::Conflict(_)) => (),
+        r => panic!("unexpected result {:?}", r),
+    }
+
+    // Reference spaces can not be redefined.
+    let axis =
+        |unit_vector: Vec<f64>| Axis::new("mm", unit_vector, NumberSet::R, 0.0, 50.0, 500).unwrap();
+    let conflicting = Space::new(
+        "space",
+        CoordinateSystem::new(
+            vec![0.0, 0.0, 0.0],
+            vec![
+                axis(vec![1.0, 0.0, 0.0]),
+                axis(vec![0.0, 1.0, 0.0]),
+                axis(vec![0.0, 0.0, 1.0]),
+            ],
+        ),
+    );
+    let core = model::build_index("other", "v1", &spaces, &[], None, None, None).unwrap();
+    match db.add_core(vec![conflicting.clone()], core) {
+        Err(crate::Error::Conflict(_)) => (),
+        r => panic!("unexpected result {:?}", r),
+    }
+    assert_eq!(db.core_keys(), vec!["core"]);
+    assert_eq!(db.space("space").unwrap().as_ref(), &spaces[0]);
+
+    // Replacing a dataset swaps its revision, while the previous one stays
+    // usable by whoever still holds it.
+    let previous = db.core("core").unwrap();
+    let objects = vec![get_object("d", &[[20.0, 20.0, 20.0]])];
+    let core = model::build_index("core", "v2", &spaces, &objects, None, None, None).unwrap();
+    let replaced = db.replace_core(spaces.clone(), core).unwrap();
+    assert_eq!(replaced.version(), "v1");
+    assert_eq!(db.core("core").unwrap().version(), "v2");
+    assert_eq!(previous.keys().unwrap().len(), 3);
+    assert_eq!(db.core("core").unwrap().keys().unwrap().len(), 1);
+
+    // Only registered datasets can be replaced, and with compatible
+    // reference spaces.
+    let core = model::build_index("other", "v1", &spaces, &[], None, None, None).unwrap();
+    match db.replace_core(spaces.clone(), core) {
+        Err(crate::Error::UnknownCore(name)) => assert_eq!(name, "other"),
+        r => panic!("unexpected result {:?}", r),
+    }
+    let core = model::build_index("core", "v3", &spaces, &[], None, None, None).unwrap();
+    assert!(db.replace_core(vec![conflicting], core).is_err());
+    assert_eq!(db.core("core").unwrap().version(), "v2");
+
+    // Removed datasets are returned.
+    assert_eq!(db.remove_core("core").unwrap().version(), "v2");
+    assert!(db.core_keys().is_empty());
+    match db.remove_core("core") {
+        Err(crate::Error::UnknownCore(name)) => assert_eq!(name, "core"),
+        r => panic!("unexpected result {:?}", r),
+    }
+}
+
+#[test]
+pub fn parallel_build() {
+    let spaces = vec![get_space()];
+
+    // Clusters of positions, so that coarser resolutions merge them.
+    let mut seed = 7u64;
+    let mut random = || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as f64 / (1u64 << 31) as f64
+    };
+    let objects = (0..40)
+        .map(|i| {
+            let center = [random() * 90.0, random() * 90.0, random() * 90.0];
+            let points = (0..50)
+                .map(|_| {
+                    let mut point = center;
+                    for c in point.iter_mut() {
+                        *c += random() * 5.0;
+                    }
+                    point
+                })
+                .collect::<Vec<_>>();
+            get_object(&format!("id{}", i % 30), &points)
+        })
+        .collect::<Vec<_>>();
+
+    // Every resolution of the index, with its positions in storage order.
+    let build = |threads| {
+        let core = model::build_index(
+            "core",
+            "v1",
+            &spaces,
+            &objects,
+            None,
+            Some(100),
+            Some(threads),
+        )
+        .unwrap();
+
+        let mut resolutions = vec![];
+        for s in core.space_db() {
+            for (r, index) in s.resolutions().iter().enumerate() {
+                resolutions.push(format!(
+                    "{} {:?} {} {:?}",
+                    s.name(),
+                    index.scale(),
+                    index.threshold(),
+                    s.objects(&spaces[0], r).unwrap()
+                ));
+            }
+        }
+
+        (core.keys().unwrap().len(), resolutions)
+    };
+
+    let serial = build(1);
+    assert!(serial.1.len() > 2);
+    assert_eq!(build(4), serial);
+}
+
+#[test]
+pub fn shared_id() {
+    let spaces = vec![get_space()];
+
+    let mut cell = get_object("x", &[[30.0, 30.0, 30.0]]);
+    cell.properties.type_name = "Cell".to_string();
+
+    let objects = vec![
+        get_object("x", &[[10.0, 10.0, 10.0], [20.0, 20.0, 20.0]]),
+        cell,
+        get_object("y", &[[30.0, 30.0, 30.0]]),
+        get_object("z", &[[10.0, 10.0, 10.0]]),
+    ];
+    let core = model::build_index("core", "v1", &spaces, &objects, None, Some(1), None).unwrap();
+
+    let db = DataBase::new(spaces, vec![]).unwrap();
+    let parameters = CoreQueryParameters {
+        db: &db,
+        output_space: None,
+        threshold_volume: None,
+        view_port: &None,
+        resolution: &None,
+        filters: &[],
+    };
+
+    // Identifiers and kinds of the results of a query by label.
+    let by_label = |type_name| {
+        let mut keys = core
+            .get_by_label(&parameters, "x", type_name)
+            .unwrap()
+            .iter()
+            .flat_map(|(_, r)| {
+                r.iter()
+                    .map(|(_, p)| format!("{} {}", p.id(), p.type_name()))
+            })
+            .collect::<Vec<_>>();
+        keys.sort_unstable();
+        keys.dedup();
+        keys
+    };
+    let by_id = |type_name| summarise(core.get_by_id(&parameters, "x", type_name).unwrap());
+
+    assert_eq!(
+        core.keys()
+            .unwrap()
+            .iter()
+            .map(|p| format!("{} {}", p.id(), p.type_name()))
+            .collect::<Vec<_>>(),
+        vec!["x Cell", "x Feature", "y Feature", "z Feature"]
+    );
+
+    // Both objects keep their own positions.
+    assert_eq!(
+        by_id(Some("Cell")),
+        vec!["space Position3([CoordinateF64(30.0), CoordinateF64(30.0), CoordinateF64(30.0)])"]
+    );
+    assert_eq!(
+        by_id(Some("Feature")),
+        vec![
+            "space Position3([CoordinateF64(10.0), CoordinateF64(10.0), CoordinateF64(10.0)])",
+            "space Position3([CoordinateF64(20.0), CoordinateF64(20.0), CoordinateF64(20.0)])"
+        ]
+    );
+    let mut all = by_id(Some("Cell"));
+    all.append(&mut by_id(Some("Feature")));
+    all.sort_unstable();
+    assert_eq!(by_id(None), all);
+    assert!(by_id(Some("Other")).is_empty());
+
+    // Labels select around the positions of their own kind only, and
+    // never return any of the objects sharing the label identifier.
+    assert_eq!(by_label(Some("Cell")), vec!["y Feature"]);
+    assert_eq!(by_label(Some("Feature")), vec!["z Feature"]);
+    assert_eq!(by_label(None), vec!["y Feature", "z Feature"]);
+    assert!(by_label(Some("Other")).is_empty());
+}
+
+#[test]
+pub fn positions_iter() {
+    let (spaces, core) = get_core();
+    let db = DataBase::new(spaces, vec![]).unwrap();
+    let space = db.space("space").unwrap();
+    let parameters = CoreQueryParameters {
+        db: &db,
+        output_space: None,
+        threshold_volume: None,
+        view_port: &None,
+        resolution: &None,
+        filters: &[],
+    };
+
+    let shapes = vec![
+        Shape::BoundingBox(
+            space.encode(&[9.0; 3]).unwrap(),
+            space.encode(&[11.5; 3]).unwrap(),
+        ),
+        // Several segments, rasterised one after the other.
+        Shape::Capsule(
+            vec![
+                space.encode(&[10.0; 3]).unwrap(),
+                space.encode(&[12.0; 3]).unwrap(),
+                space.encode(&[14.0, 12.0, 12.0]).unwrap(),
+            ],
+            3.0.into(),
+        ),
+    ];
+
+    let mut found = vec![];
+    for shape in &shapes {
+        let streamed = core
+            .get_by_positions_iter(&parameters, shape.rasterise_iter().unwrap(), "space")
+            .unwrap();
+        let collected = core
+            .get_by_positions(&parameters, &shape.rasterise().unwrap(), "space")
+            .unwrap();
+
+        found.push(summarise(streamed));
+        assert_eq!(found.last(), Some(&summarise(collected)));
+    }
+
+    assert_eq!(found[0].len(), 2);
+    assert_eq!(found[1].len(), 3);
+}
+
+#[test]
+pub fn mesh_universe() {
+    let (spaces, core) = get_core();
+    let db = DataBase::new(spaces, vec![]).unwrap();
+    let parameters = CoreQueryParameters {
+        db: &db,
+        output_space: None,
+        threshold_volume: None,
+        view_port: &None,
+        resolution: &None,
+        filters: &[],
+    };
+
+    // Cube around the positions of `b`, in meters, with its faces
+    // oriented outwards.
+    let (lower, higher) = (0.0095, 0.0115);
+    let vertices = (0..8)
+        .map(|i| {
+            (0..3)
+                .map(|k| if i & (1 << k) == 0 { lower } else { higher })
+                .collect::<Vec<_>>()
+                .into()
+        })
+        .collect();
+    let faces = vec![
+        [0, 2, 3],
+        [0, 3, 1],
+        [4, 5, 7],
+        [4, 7, 6],
+        [0, 1, 5],
+        [0, 5, 4],
+        [2, 6, 7],
+        [2, 7, 3],
+        [0, 4, 6],
+        [0, 6, 2],
+        [1, 3, 7],
+        [1, 7, 5],
+    ];
+    let mesh = Shape::Mesh(vertices, faces);
+    let cube = Shape::BoundingBox(vec![lower; 3].into(), vec![higher; 3].into());
+
+    let results = summarise(core.get_by_shape(&parameters, &mesh, "Universe").unwrap());
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| r.contains("Feature(\"b\")")));
+    assert_eq!(
+        results,
+        summarise(core.get_by_shape(&parameters, &cube, "Universe").unwrap())
+    );
+}
+
+#[test]
+pub fn nearest_coarse() {
+    let spaces = vec![get_space()];
+
+    // Clusters of positions, so that the index has coarser resolutions.
+    let cluster = |id, center: [f64; 3]| {
+        let points = (0..64)
+            .map(|i| {
+                let mut point = center;
+                for (k, c) in point.iter_mut().enumerate() {
+                    *c += ((i >> (2 * k)) & 3) as f64;
+                }
+                point
+            })
+            .collect::<Vec<_>>();
+        get_object(id, &points)
+    };
+    let objects = vec![
+        cluster("a", [20.0, 20.0, 20.0]),
+        cluster("b", [60.0, 60.0, 60.0]),
+        cluster("c", [80.0, 20.0, 20.0]),
+    ];
+    let core = model::build_index("core", "v1", &spaces, &objects, None, Some(10), None).unwrap();
+
+    let resolutions = core.space_db()[0].resolutions();
+    let scale = resolutions.last().unwrap().scale()[0];
+    assert!(resolutions.len() > 1 && scale > 0);
+
+    let db = DataBase::new(spaces, vec![core]).unwrap();
+    let core = db.core("core").unwrap();
+    let nearest = |resolution: &Option<Vec<u32>>, position: [f64; 3]| {
+        let parameters = CoreQueryParameters {
+            db: &db,
+            output_space: None,
+            threshold_volume: None,
+            view_port: &None,
+            resolution,
+            filters: &[],
+        };
+        core.get_nearest(&parameters, &position.to_vec().into(), 3, "space")
+            .unwrap()
+            .into_iter()
+            .map(|(_, position, properties, distance)| {
+                let position: Vec<f64> = position.into();
+                (properties.id().to_string(), position, distance)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // The coarsest resolution, used by default, approximates positions
+    // by the center of their cell, in full resolution coordinates.
+    let cell = (1u64 << scale) as f64 * 0.1;
+    for position in &[[0.0; 3], [99.0; 3], [70.0, 30.0, 10.0]] {
+        let full = nearest(&Some(vec![0, 0, 0]), *position);
+        let coarse = nearest(&None, *position);
+
+        assert_eq!(
+            coarse.iter().map(|r| &r.0).collect::<Vec<_>>(),
+            full.iter().map(|r| &r.0).collect::<Vec<_>>()
+        );
+        for (coarse, full) in coarse.iter().zip(full.iter()) {
+            for k in 0..3 {
+                assert!((coarse.1[k] - full.1[k]).abs() <= cell);
+            }
+            // Distances are in meters.
+            assert!((coarse.2 - full.2).abs() <= 3f64.sqrt() * cell * 1e-3);
+        }
+    }
+}
+
+#[test]
+pub fn distance_join_coarse() {
+    let spaces = vec![get_space()];
+    let scales = Some(vec![vec![0, 0, 0], vec![3, 3, 3]]);
+    let core = |name: &str, objects: &[model::SpatialObject]| {
+        model::build_index(name, "v1", &spaces, objects, scales.clone(), None, None).unwrap()
+    };
+    let left = core(
+        "left",
+        &[
+            get_object("l", &[[45.0, 45.0, 45.0]]),
+            get_object("m", &[[80.0, 80.0, 80.0]]),
+        ],
+    );
+    let right = core(
+        "right",
+        &[
+            get_object("r1", &[[47.0, 45.0, 45.0]]),
+            get_object("r2", &[[60.0, 45.0, 45.0]]),
+            get_object("r3", &[[84.0, 80.0, 80.0]]),
+        ],
+    );
+    assert_eq!(left.space_db()[0].resolutions().len(), 2);
+    let db = DataBase::new(spaces.clone(), vec![left, right]).unwrap();
+
+    let join = |resolution: &Option<Vec<u32>>| {
+        let parameters = CoreQueryParameters {
+            db: &db,
+            output_space: None,
+            threshold_volume: None,
+            view_port: &None,
+            resolution,
+            filters: &[],
+        };
+        db.join_by_distance(&parameters, "left", "right", 0.005)
+            .unwrap()
+            .into_iter()
+            .map(|(a, b, d)| (format!("{}-{}", a.id(), b.id()), d))
+            .collect::<Vec<_>>()
+    };
+
+    // The coarsest resolution, used by default, approximates positions
+    // by the center of their cell, in full resolution coordinates.
+    let full = join(&Some(vec![0, 0, 0]));
+    let coarse = join(&None);
+    assert_eq!(
+        full.iter().map(|p| p.0.as_str()).collect::<Vec<_>>(),
+        vec!["l-r1", "m-r3"]
+    );
+    assert_eq!(
+        coarse.iter().map(|p| &p.0).collect::<Vec<_>>(),
+        full.iter().map(|p| &p.0).collect::<Vec<_>>()
+    );
+
+    // Distances are in meters, and cells of the coarsest resolution
+    // span 8 steps of 0.1 mm.
+    for (coarse, full) in coarse.iter().zip(full.iter()) {
+        assert!((coarse.1 - full.1).abs() <= 3f64.sqrt() * 0.8e-3);
+    }
+}
@@ -196,6 +196,7 @@ fn convert(string: &str) -> Result<Vec<SpatialObject>, Error> {
             let properties = Properties {
                 type_name: "Feature".to_string(),
                 id: k,
+                attributes: Default::default(),
             };
 
             let shapes = v